@@ -0,0 +1,98 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use fuse_rust::Fuse;
+use std::hint::black_box;
+
+/// Deterministic list of random lowercase words, so runs are comparable.
+fn random_strings(count: usize, len: usize) -> Vec<String> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    (0..count)
+        .map(|_| {
+            (0..len)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (b'a' + (state % 26) as u8) as char
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn search(c: &mut Criterion) {
+    let fuse = Fuse::default();
+    let list = random_strings(10_000, 32);
+    let pattern = fuse.create_pattern("fuzzy search");
+
+    let mut group = c.benchmark_group("search");
+    group.bench_function("fuse", |b| {
+        b.iter(|| {
+            for item in &list {
+                black_box(fuse.search(pattern.as_ref(), item));
+            }
+        })
+    });
+    group.bench_function("searcher", |b| {
+        let mut searcher = fuse.searcher();
+        b.iter(|| {
+            for item in &list {
+                black_box(searcher.search(pattern.as_ref(), item));
+            }
+        })
+    });
+    group.bench_function("searcher_score", |b| {
+        let mut searcher = fuse.searcher();
+        b.iter(|| {
+            for item in &list {
+                black_box(searcher.score(pattern.as_ref(), item));
+            }
+        })
+    });
+    group.bench_function("iterable", |b| {
+        b.iter(|| black_box(fuse.search_text_in_iterable("fuzzy search", &list)))
+    });
+    group.bench_function("search_many", |b| {
+        let queries = random_strings(16, 8);
+        b.iter(|| black_box(fuse.search_many(&queries, &list)))
+    });
+    group.finish();
+}
+
+fn parallel(c: &mut Criterion) {
+    let fuse = Fuse::default();
+    let list = random_strings(100_000, 32);
+    let list: Vec<&str> = list.iter().map(String::as_str).collect();
+
+    let mut group = c.benchmark_group("parallel");
+    group.sample_size(20);
+    group.bench_function("sequential", |b| {
+        b.iter(|| black_box(fuse.search_text_in_iterable("fuzzy search", &list)))
+    });
+    for (name, chunk_size) in [
+        ("chunk_16", Some(16)),
+        ("chunk_100000", Some(100_000)),
+        ("adaptive", None),
+    ] {
+        #[cfg(feature = "rayon")]
+        group.bench_function(format!("rayon_{}", name), |b| {
+            b.iter(|| {
+                fuse.search_text_in_string_list_rayon("fuzzy search", &list, chunk_size, &|x| {
+                    black_box(x);
+                })
+            })
+        });
+        #[cfg(feature = "async")]
+        group.bench_function(format!("threads_{}", name), |b| {
+            b.iter(|| {
+                fuse.search_text_in_string_list("fuzzy search", &list, chunk_size, &|x| {
+                    black_box(x);
+                })
+            })
+        });
+        let _ = (name, chunk_size);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, search, parallel);
+criterion_main!(benches);
@@ -0,0 +1,292 @@
+#![warn(missing_docs)]
+
+//! Fuse-RS Python bindings
+//!
+//! A `fuse_rust` Python module wrapping `Fuse`, so results are scored exactly like the Rust library.
+//! Searches release the GIL, and ranges are indices of characters so they can be used to slice Python strings.
+
+use fuse::{FieldValue, FuseBuilder, FuseField, Record};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Converts byte ranges of a string to ranges of characters, the indices of Python strings.
+fn char_ranges(string: &str, ranges: &[Range<usize>]) -> Vec<(usize, usize)> {
+    let char_len = |end: usize| string[..end].chars().count();
+    ranges
+        .iter()
+        .map(|range| (char_len(range.start), char_len(range.end)))
+        .collect()
+}
+
+/// A row of a dict based search, with the string values of every key.
+struct Row(HashMap<String, Vec<String>>);
+
+impl Record for Row {
+    fn lookup_path(&self, path: &str) -> Option<FieldValue<'_>> {
+        self.0.get(path).map(|values| {
+            FieldValue::Many(
+                values
+                    .iter()
+                    .map(|value| Cow::from(value.as_str()))
+                    .collect(),
+            )
+        })
+    }
+}
+
+impl Row {
+    /// Collects the searchable values of a dict, strings or lists of strings, other values are skipped.
+    fn extract(dict: &Bound<'_, PyDict>, keys: &[FuseField]) -> PyResult<Self> {
+        let mut values = HashMap::new();
+        for key in keys {
+            let Some(value) = dict.get_item(key.name.as_ref())? else {
+                continue;
+            };
+            if let Ok(value) = value.extract::<String>() {
+                values.insert(key.name.to_string(), vec![value]);
+            } else if let Ok(list) = value.extract::<Vec<String>>() {
+                values.insert(key.name.to_string(), list);
+            }
+        }
+        Ok(Self(values))
+    }
+}
+
+/// Result of a search in a list of strings.
+#[pyclass(frozen, get_all)]
+pub struct SearchResult {
+    /// index of the string in the list
+    index: usize,
+    /// score of the string, 0.0 is a perfect match 1.0 is a perfect mismatch
+    score: f64,
+    /// `(start, end)` character indices of the matched ranges
+    ranges: Vec<(usize, usize)>,
+}
+
+#[pymethods]
+impl SearchResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "SearchResult(index={}, score={}, ranges={:?})",
+            self.index, self.score, self.ranges
+        )
+    }
+}
+
+/// Match of a single key of a dict.
+#[pyclass(frozen, get_all)]
+pub struct KeyMatch {
+    /// the matched key
+    key: String,
+    /// weighted score of the key
+    score: f64,
+    /// `(start, end)` character indices of the matched ranges in the value of the key
+    ranges: Vec<(usize, usize)>,
+}
+
+#[pymethods]
+impl KeyMatch {
+    fn __repr__(&self) -> String {
+        format!(
+            "KeyMatch(key={:?}, score={}, ranges={:?})",
+            self.key, self.score, self.ranges
+        )
+    }
+}
+
+/// Result of a search in a list of dicts.
+#[pyclass(frozen, get_all)]
+pub struct KeySearchResult {
+    /// index of the dict in the list
+    index: usize,
+    /// score of the dict, the average weighted score of its matched keys
+    score: f64,
+    /// match of every matched key
+    matches: Vec<Py<KeyMatch>>,
+}
+
+#[pymethods]
+impl KeySearchResult {
+    fn __repr__(&self) -> String {
+        let matches: Vec<String> = self
+            .matches
+            .iter()
+            .map(|matched| matched.get().__repr__())
+            .collect();
+        format!(
+            "KeySearchResult(index={}, score={}, matches=[{}])",
+            self.index,
+            self.score,
+            matches.join(", ")
+        )
+    }
+}
+
+/// Fuzzy searches lists of strings or dicts, with the same config and scoring as the Rust library.
+///
+/// Every argument is optional and defaults to the one of `Fuse::default`, invalid values raise a `ValueError`.
+#[pyclass(frozen)]
+pub struct Fuse {
+    fuse: fuse::Fuse,
+}
+
+fn config_error(error: fuse::ConfigError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+fn search_results(list: &[String], results: Vec<fuse::SearchResult>) -> Vec<SearchResult> {
+    results
+        .into_iter()
+        .map(|result| SearchResult {
+            index: result.index,
+            score: result.score,
+            ranges: char_ranges(&list[result.index], &result.ranges),
+        })
+        .collect()
+}
+
+#[pymethods]
+impl Fuse {
+    #[new]
+    #[pyo3(signature = (
+        *,
+        location = 0,
+        distance = 100,
+        threshold = 0.6,
+        is_case_sensitive = false,
+        tokenize = false,
+        min_match_char_length = 1,
+        find_all_matches = false,
+        should_sort = true,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        location: i32,
+        distance: i32,
+        threshold: f64,
+        is_case_sensitive: bool,
+        tokenize: bool,
+        min_match_char_length: usize,
+        find_all_matches: bool,
+        should_sort: bool,
+    ) -> PyResult<Self> {
+        let fuse = FuseBuilder::new()
+            .location(location)
+            .and_then(|builder| builder.distance(distance))
+            .and_then(|builder| builder.threshold(threshold))
+            .and_then(|builder| builder.min_match_char_length(min_match_char_length))
+            .map_err(config_error)?
+            .is_case_sensitive(is_case_sensitive)
+            .tokenize(tokenize)
+            .find_all_matches(find_all_matches)
+            .should_sort(should_sort)
+            .build();
+        Ok(Self { fuse })
+    }
+
+    /// Searches for a text pattern in a list of strings.
+    /// Returns a list of `SearchResult`, sorted by score.
+    fn search(&self, py: Python<'_>, text: &str, list: Vec<String>) -> Vec<SearchResult> {
+        let results = py.detach(|| self.fuse.search_text_in_iterable(text, &list));
+        search_results(&list, results)
+    }
+
+    /// Searches for a text pattern in a list of strings, split into chunks searched in parallel.
+    /// The chunk size is picked from the length of the list and the number of threads, unless given.
+    /// Returns a list of `SearchResult`, sorted by score, identical to the one of `search`.
+    #[pyo3(signature = (text, list, chunk_size = None))]
+    fn search_parallel(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        list: Vec<String>,
+        chunk_size: Option<usize>,
+    ) -> Vec<SearchResult> {
+        let results = py.detach(|| {
+            let list: Vec<&str> = list.iter().map(String::as_str).collect();
+            let results = Cell::new(vec![]);
+            self.fuse
+                .search_text_in_string_list_rayon(text, &list, chunk_size, &|found| {
+                    results.set(found)
+                });
+            results.into_inner()
+        });
+        search_results(&list, results)
+    }
+
+    /// Searches for a text pattern in the values of some keys of a list of dicts.
+    /// Keys are either names, with a weight of 1, or `(name, weight)` tuples.
+    /// Values are strings or lists of strings, matched by their best value, other values are skipped.
+    /// Returns a list of `KeySearchResult`, sorted by score.
+    fn search_keys(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        list: Vec<Bound<'_, PyDict>>,
+        keys: Vec<Bound<'_, PyAny>>,
+    ) -> PyResult<Vec<KeySearchResult>> {
+        let keys: Vec<FuseField> = keys
+            .iter()
+            .map(|key| {
+                let (name, weight) = match key.extract::<String>() {
+                    Ok(name) => (name, 1.0),
+                    Err(_) => key.extract::<(String, f64)>()?,
+                };
+                Ok(FuseField {
+                    name: name.into(),
+                    weight,
+                })
+            })
+            .collect::<PyResult<_>>()?;
+        let rows: Vec<Row> = list
+            .iter()
+            .map(|dict| Row::extract(dict, &keys))
+            .collect::<PyResult<_>>()?;
+
+        let results = py.detach(|| self.fuse.search_text_in_records(text, &rows, &keys));
+        results
+            .into_iter()
+            .map(|result| {
+                let row = &rows[result.index];
+                let matches = result
+                    .results
+                    .into_iter()
+                    .map(|matched| {
+                        let ranges = row.0[&matched.value]
+                            .get(matched.value_index)
+                            .map(|value| char_ranges(value, &matched.ranges))
+                            .unwrap_or_default();
+                        Py::new(
+                            py,
+                            KeyMatch {
+                                key: matched.value,
+                                score: matched.score,
+                                ranges,
+                            },
+                        )
+                    })
+                    .collect::<PyResult<_>>()?;
+                Ok(KeySearchResult {
+                    index: result.index,
+                    score: result.score,
+                    matches,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Fuzzy search with the scoring of the fuse-rust Rust library.
+#[pymodule]
+fn fuse_rust(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Fuse>()?;
+    module.add_class::<SearchResult>()?;
+    module.add_class::<KeySearchResult>()?;
+    module.add_class::<KeyMatch>()?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+import pytest
+
+from fuse_rust import Fuse
+
+BOOKS = [
+    "The Silmarillion",
+    "The Lock Artist",
+    "The Lost Symbol",
+    "Old Man's War",
+]
+
+
+def test_search():
+    results = Fuse().search("Te silm", BOOKS)
+    assert results[0].index == 0
+    assert all(a.score <= b.score for a, b in zip(results, results[1:]))
+
+
+def test_ranges_are_character_indices():
+    [result] = Fuse(threshold=0.2).search("war", ["😀 Old Man's War"])
+    assert result.ranges == [(12, 15)]
+    assert "😀 Old Man's War"[12:15] == "War"
+
+
+def test_search_parallel_matches_search():
+    fuse = Fuse(tokenize=True)
+    strings = [f"{book} {i}" for i in range(500) for book in BOOKS]
+    expected = [(r.index, r.score, r.ranges) for r in fuse.search("lost sym", strings)]
+    for chunk_size in [None, 0, 7, 10_000]:
+        results = fuse.search_parallel("lost sym", strings, chunk_size)
+        assert [(r.index, r.score, r.ranges) for r in results] == expected
+
+
+def test_search_keys():
+    books = [
+        {"title": "Old Man's War", "author": "John Scalzi", "year": 2005},
+        {"title": "Good Omens", "author": ["Terry Pratchett", "Neil Gaiman"]},
+        {"title": "The Lock Artist"},
+    ]
+    results = Fuse(threshold=0.2).search_keys("gaiman", books, [("title", 0.3), "author"])
+    assert len(results) == 1
+    assert results[0].index == 1
+    [match] = results[0].matches
+    assert match.key == "author"
+    assert match.ranges == [(5, 11)]
+
+
+def test_invalid_config():
+    with pytest.raises(ValueError, match="Invalid Threshold"):
+        Fuse(threshold=1.5)
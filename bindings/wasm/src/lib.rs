@@ -0,0 +1,220 @@
+#![warn(missing_docs)]
+
+//! Fuse-RS WebAssembly bindings
+//!
+//! Exposes the fuzzy search of fuse-rust to JavaScript, so a web frontend ranks results exactly like a Rust backend.
+//! Results are plain JS objects, with ranges in UTF-16 code units so they can be used to slice JS strings.
+
+use fuse_rust::{FResult, FuseBuilder, FuseField, TieBreaker};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use wasm_bindgen::prelude::*;
+
+/// Config of a `Fuse` instance, every field is optional and defaults to the one of `Fuse::default`.
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+struct Options {
+    location: Option<i32>,
+    distance: Option<i32>,
+    threshold: Option<f64>,
+    is_case_sensitive: Option<bool>,
+    tokenize: Option<bool>,
+    min_match_char_length: Option<usize>,
+    find_all_matches: Option<bool>,
+    should_sort: Option<bool>,
+    tie_breaker: Option<TieBreakerOption>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TieBreakerOption {
+    Index,
+    Length,
+}
+
+/// A key to search in objects, either its path or its path and weight.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyOption {
+    Path(String),
+    Weighted { name: String, weight: f64 },
+}
+
+/// Result of a search in a list of strings.
+#[derive(Serialize)]
+struct SearchResult {
+    index: usize,
+    score: f64,
+    ranges: Vec<[usize; 2]>,
+}
+
+/// Result of a search in a list of objects.
+#[derive(Serialize)]
+struct KeySearchResult {
+    index: usize,
+    score: f64,
+    matches: Vec<KeyMatch>,
+}
+
+/// Match of a single key of an object.
+#[derive(Serialize)]
+struct KeyMatch {
+    key: String,
+    score: f64,
+    ranges: Vec<[usize; 2]>,
+}
+
+/// Converts byte ranges of a string to ranges of UTF-16 code units, the indices of JS strings.
+fn utf16_ranges(string: &str, ranges: &[Range<usize>]) -> Vec<[usize; 2]> {
+    let utf16_len = |end: usize| string[..end].encode_utf16().count();
+    ranges
+        .iter()
+        .map(|range| [utf16_len(range.start), utf16_len(range.end)])
+        .collect()
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    Ok(value.serialize(&serializer)?)
+}
+
+/// Fuzzy searches lists of strings or objects, with the same config and ranking as fuse-rust.
+/// # Examples:
+/// ```js
+/// import { Fuse } from "fuse-rust-wasm";
+/// const fuse = new Fuse({ threshold: 0.4, tokenize: true });
+/// fuse.search("od mn war", ["Old Man's War", "The Lock Artist"]);
+/// fuse.searchKeys("man", books, [{ name: "title", weight: 0.3 }, "author.name"]);
+/// ```
+#[wasm_bindgen]
+pub struct Fuse {
+    fuse: fuse_rust::Fuse,
+}
+
+#[wasm_bindgen]
+impl Fuse {
+    /// Creates a fuse instance from an options object, such as `{ threshold: 0.4, tokenize: true }`.
+    /// Options are validated like `FuseBuilder`, throwing an error for an invalid or unknown option.
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<Fuse, JsError> {
+        let options: Options = if options.is_undefined() || options.is_null() {
+            Options::default()
+        } else {
+            serde_wasm_bindgen::from_value(options)?
+        };
+
+        let mut builder = FuseBuilder::new();
+        if let Some(location) = options.location {
+            builder = builder.location(location)?;
+        }
+        if let Some(distance) = options.distance {
+            builder = builder.distance(distance)?;
+        }
+        if let Some(threshold) = options.threshold {
+            builder = builder.threshold(threshold)?;
+        }
+        if let Some(min_match_char_length) = options.min_match_char_length {
+            builder = builder.min_match_char_length(min_match_char_length)?;
+        }
+        if let Some(is_case_sensitive) = options.is_case_sensitive {
+            builder = builder.is_case_sensitive(is_case_sensitive);
+        }
+        if let Some(tokenize) = options.tokenize {
+            builder = builder.tokenize(tokenize);
+        }
+        if let Some(find_all_matches) = options.find_all_matches {
+            builder = builder.find_all_matches(find_all_matches);
+        }
+        if let Some(should_sort) = options.should_sort {
+            builder = builder.should_sort(should_sort);
+        }
+        if let Some(tie_breaker) = options.tie_breaker {
+            builder = builder.tie_breaker(match tie_breaker {
+                TieBreakerOption::Index => TieBreaker::Index,
+                TieBreakerOption::Length => TieBreaker::Length,
+            });
+        }
+        Ok(Fuse {
+            fuse: builder.build(),
+        })
+    }
+
+    /// Searches for a text pattern in an array of strings, see `Fuse::search_text_in_iterable`.
+    /// - Returns: An array of `{ index, score, ranges }` objects, with ranges as `[start, end]` UTF-16 indices.
+    pub fn search(&self, text: &str, list: Vec<String>) -> Result<JsValue, JsError> {
+        let results: Vec<SearchResult> = self
+            .fuse
+            .search_text_in_iterable(text, &list)
+            .into_iter()
+            .map(|result| SearchResult {
+                index: result.index,
+                score: result.score,
+                ranges: utf16_ranges(&list[result.index], &result.ranges),
+            })
+            .collect();
+        to_js(&results)
+    }
+
+    /// Searches for a text pattern in the keys of an array of objects, see `Fuse::search_text_in_records`.
+    /// Keys are paths such as `"author.name"`, or `{ name, weight }` objects, keys without a weight have a weight of 1.
+    /// - Returns: An array of `{ index, score, matches }` objects, with a `{ key, score, ranges }` match per matched key.
+    #[wasm_bindgen(js_name = searchKeys)]
+    pub fn search_keys(
+        &self,
+        text: &str,
+        list: JsValue,
+        keys: JsValue,
+    ) -> Result<JsValue, JsError> {
+        let list: Vec<serde_json::Value> = serde_wasm_bindgen::from_value(list)?;
+        let keys: Vec<KeyOption> = serde_wasm_bindgen::from_value(keys)?;
+        let keys: Vec<FuseField> = keys
+            .into_iter()
+            .map(|key| match key {
+                KeyOption::Path(name) => FuseField {
+                    name: name.into(),
+                    weight: 1.0,
+                },
+                KeyOption::Weighted { name, weight } => FuseField {
+                    name: name.into(),
+                    weight,
+                },
+            })
+            .collect();
+
+        let results: Vec<KeySearchResult> = self
+            .fuse
+            .search_text_in_records(text, &list, &keys)
+            .into_iter()
+            .map(|result| KeySearchResult {
+                index: result.index,
+                score: result.score,
+                matches: result
+                    .results
+                    .into_iter()
+                    .map(|matched| key_match(&list[result.index], matched))
+                    .collect(),
+            })
+            .collect();
+        to_js(&results)
+    }
+}
+
+/// Converts the match of a key, converting its ranges with the value that matched.
+fn key_match(record: &serde_json::Value, matched: FResult) -> KeyMatch {
+    use fuse_rust::Record;
+
+    let ranges = record
+        .lookup_path(&matched.value)
+        .and_then(|values| {
+            values
+                .as_slice()
+                .get(matched.value_index)
+                .map(|value| utf16_ranges(value, &matched.ranges))
+        })
+        .unwrap_or_default();
+    KeyMatch {
+        key: matched.value,
+        score: matched.score,
+        ranges,
+    }
+}
@@ -0,0 +1,92 @@
+//! Run with `wasm-pack test --node bindings/wasm`.
+#![cfg(target_arch = "wasm32")]
+
+use fuse_rust_wasm::Fuse;
+use serde::Deserialize;
+use serde_json::json;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct SearchResult {
+    index: usize,
+    score: f64,
+    ranges: Vec<[usize; 2]>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct KeyMatch {
+    key: String,
+    score: f64,
+    ranges: Vec<[usize; 2]>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct KeySearchResult {
+    index: usize,
+    score: f64,
+    matches: Vec<KeyMatch>,
+}
+
+fn js(value: serde_json::Value) -> JsValue {
+    serde_wasm_bindgen::to_value(&value).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn search_ranks_like_rust() {
+    let books = [
+        "The Silmarillion",
+        "The Lock Artist",
+        "The Lost Symbol",
+        "Old Man's War",
+    ]
+    .map(String::from);
+    let fuse = Fuse::new(js(json!({ "threshold": 0.5, "tokenize": true }))).unwrap();
+    let results: Vec<SearchResult> =
+        serde_wasm_bindgen::from_value(fuse.search("te silm", books.to_vec()).unwrap()).unwrap();
+
+    let expected = fuse_rust::Fuse {
+        threshold: 0.5,
+        tokenize: true,
+        ..Default::default()
+    }
+    .search_text_in_iterable("te silm", &books);
+    assert_eq!(results.len(), expected.len());
+    for (result, expected) in results.iter().zip(&expected) {
+        assert_eq!(result.index, expected.index);
+        assert_eq!(result.score, expected.score);
+    }
+}
+
+#[wasm_bindgen_test]
+fn ranges_are_utf16_indices() {
+    let fuse = Fuse::new(JsValue::UNDEFINED).unwrap();
+    let results: Vec<SearchResult> = serde_wasm_bindgen::from_value(
+        fuse.search("war", vec!["😀 Old Man's War".into()]).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(results[0].ranges, vec![[13, 16]]);
+}
+
+#[wasm_bindgen_test]
+fn search_keys() {
+    let books = js(json!([
+        { "title": "Old Man's War", "author": { "name": "John Scalzi" } },
+        { "title": "Good Omens", "author": [{ "name": "Terry Pratchett" }, { "name": "Neil Gaiman" }] },
+    ]));
+    let keys = js(json!([{ "name": "title", "weight": 0.3 }, "author.name"]));
+    let fuse = Fuse::new(js(json!({ "threshold": 0.2 }))).unwrap();
+    let results: Vec<KeySearchResult> =
+        serde_wasm_bindgen::from_value(fuse.search_keys("gaiman", books, keys).unwrap()).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].index, 1);
+    assert_eq!(results[0].matches[0].key, "author.name");
+    assert_eq!(results[0].matches[0].ranges, vec![[5, 11]]);
+}
+
+#[wasm_bindgen_test]
+fn invalid_options_throw() {
+    assert!(Fuse::new(js(json!({ "threshold": 2.0 }))).is_err());
+    assert!(Fuse::new(js(json!({ "treshold": 0.2 }))).is_err());
+}
@@ -108,5 +108,5 @@ fn main() {
         "itovolfijo",
     ];
 
-    fuse.search_text_in_string_list("aa", &random_strings, 10 as usize, &test);
+    fuse.search_text_in_string_list("aa", &random_strings, 10, &test);
 }
@@ -28,6 +28,7 @@ impl Fuseable for Book<'_> {
         }
     }
 }
+#[allow(clippy::single_range_in_vec_init)]
 fn main() {
     let books = [
         Book {
@@ -51,6 +52,7 @@ fn main() {
                 score: 0.015000000000000003,
                 results: vec!(FResult {
                     value: String::from("author"),
+                    value_index: 0,
                     score: 0.015000000000000003,
                     ranges: vec!((5..8)),
                 }),
@@ -60,6 +62,7 @@ fn main() {
                 score: 0.027999999999999997,
                 results: vec!(FResult {
                     value: String::from("title"),
+                    value_index: 0,
                     score: 0.027999999999999997,
                     ranges: vec!((4..7)),
                 })
@@ -11,17 +11,17 @@ fn main() {
             SearchResult {
                 index: 0,
                 score: 0.14285714285714285,
-                ranges: vec!((0..1), (2..8), (10..14)),
+                ranges: vec!((0..1), (2..8)),
             },
             SearchResult {
                 index: 2,
                 score: 0.49857142857142855,
-                ranges: vec!((0..1), (2..5), (6..10), (11..12), (14..15)),
+                ranges: vec!((7..10), (11..12)),
             },
             SearchResult {
                 index: 1,
                 score: 0.5714285714285714,
-                ranges: vec!((0..1), (2..5), (8..9), (11..15)),
+                ranges: vec!((0..1), (2..5)),
             },
         ),
         "Iterable search returned incorrect results"
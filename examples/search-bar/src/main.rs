@@ -6,7 +6,7 @@ use fuse_rust::{Fuse, SearchResult};
 
 use std::ops::Range;
 
-const BOOKS: &'static [&'static str] = &[
+const BOOKS: &[&str] = &[
     "Angels & Demons",
     "Old Man's War",
     "The Lock Artist",
@@ -78,10 +78,10 @@ impl Example<'_> {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let query_box = text_input("Search query:", &self.search_query)
             .id(INPUT_ID.clone())
-            .on_input(|input| Message::SearchQuery(input))
+            .on_input(Message::SearchQuery)
             .padding(15)
             .size(16);
 
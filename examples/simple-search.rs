@@ -10,7 +10,7 @@ fn main() {
         result,
         Some(ScoreResult {
             score: 0.4444444444444444,
-            ranges: vec!((0..1), (2..7), (9..13)),
+            ranges: vec!((0..1), (2..5), (6..7), (9..13)),
         }),
         "Simple search returned incorrect results"
     );
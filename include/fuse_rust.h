@@ -0,0 +1,130 @@
+/*
+ * C API of fuse-rust, generated by cbindgen, do not edit.
+ *
+ * Build the library with the `capi` feature, as a shared or a static library:
+ *   cargo rustc --release --features capi --crate-type cdylib
+ *   cargo rustc --release --features capi --crate-type staticlib
+ * then link against target/release/libfuse_rust.so (.dylib, .dll) or libfuse_rust.a.
+ */
+
+#ifndef FUSE_RUST_H
+#define FUSE_RUST_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Status returned by the functions of the C API.
+typedef enum FuseStatus {
+  // the call succeeded
+  FUSE_STATUS_OK = 0,
+  // a required pointer argument was null
+  FUSE_STATUS_NULL_POINTER = 1,
+  // a string argument was not valid UTF-8
+  FUSE_STATUS_INVALID_UTF8 = 2,
+  // a config value was invalid, the config is unchanged
+  FUSE_STATUS_INVALID_CONFIG = 3,
+  // an index was out of bounds
+  FUSE_STATUS_OUT_OF_BOUNDS = 4,
+  // the call panicked, a search returns no results and a config is left unchanged
+  FUSE_STATUS_PANIC = 5,
+} FuseStatus;
+
+// A fuse instance, created with `fuse_new` and freed with `fuse_free`.
+typedef struct Fuse Fuse;
+
+// The results of a search, sorted by score, freed with `fuse_results_free`.
+typedef struct FuseResults FuseResults;
+
+// Byte range of matched characters in a searched string.
+typedef struct FuseRange {
+  // byte offset of the first matched byte
+  size_t start;
+  // byte offset after the last matched byte
+  size_t end;
+} FuseRange;
+
+// A search result, borrowed from the `FuseResults` it was read from.
+typedef struct FuseResult {
+  // index of the string in the searched list
+  size_t index;
+  // score of the string, 0.0 is a perfect match 1.0 is a perfect mismatch
+  double score;
+  // matched ranges, valid until the `FuseResults` are freed
+  const struct FuseRange *ranges;
+  // number of matched ranges
+  size_t ranges_len;
+} FuseResult;
+
+// Creates a fuse instance with the default config, to be freed with `fuse_free`. Returns null if it fails.
+struct Fuse *fuse_new(void);
+
+// Frees a fuse instance created by `fuse_new`. Does nothing if `fuse` is null.
+//
+// # Safety
+// `fuse` must be null or a pointer returned by `fuse_new`, not already freed.
+void fuse_free(struct Fuse *fuse);
+
+// Sets the score above which the search gives up, between `0.0` and `1.0`.
+//
+// # Safety
+// `fuse` must be null or a valid pointer returned by `fuse_new`.
+enum FuseStatus fuse_set_threshold(struct Fuse *fuse, double threshold);
+
+// Sets where in the string the pattern is expected to be found, at least `0`.
+//
+// # Safety
+// `fuse` must be null or a valid pointer returned by `fuse_new`.
+enum FuseStatus fuse_set_location(struct Fuse *fuse, int32_t location);
+
+// Sets how far from the location a match scores as a complete mismatch, at least `0`.
+//
+// # Safety
+// `fuse` must be null or a valid pointer returned by `fuse_new`.
+enum FuseStatus fuse_set_distance(struct Fuse *fuse, int32_t distance);
+
+// Sets whether lowercase and uppercase are matched separately.
+//
+// # Safety
+// `fuse` must be null or a valid pointer returned by `fuse_new`.
+enum FuseStatus fuse_set_is_case_sensitive(struct Fuse *fuse, bool is_case_sensitive);
+
+// Sets whether the words of the search patterns are also matched on their own.
+//
+// # Safety
+// `fuse` must be null or a valid pointer returned by `fuse_new`.
+enum FuseStatus fuse_set_tokenize(struct Fuse *fuse, bool tokenize);
+
+// Searches for a text pattern in a list of strings, see `Fuse::search_text_in_iterable`.
+// On success, `*out` is set to the results, to be freed with `fuse_results_free`, otherwise it is left untouched.
+//
+// # Safety
+// `fuse` must be a valid pointer returned by `fuse_new`, `text` a nul terminated string,
+// `list` an array of `len` nul terminated strings, or null if `len` is `0`, and `out` a valid pointer.
+enum FuseStatus fuse_search_strings(const struct Fuse *fuse,
+                                    const char *text,
+                                    const char *const *list,
+                                    size_t len,
+                                    struct FuseResults **out);
+
+// Returns the number of results, `0` if `results` is null.
+//
+// # Safety
+// `results` must be null or a valid pointer returned by `fuse_search_strings`.
+size_t fuse_results_len(const struct FuseResults *results);
+
+// Reads the result at `index` into `*out`. Its `ranges` are valid until `results` is freed.
+//
+// # Safety
+// `results` must be null or a valid pointer returned by `fuse_search_strings`, and `out` null or a valid pointer.
+enum FuseStatus fuse_results_get(const struct FuseResults *results,
+                                 size_t index,
+                                 struct FuseResult *out);
+
+// Frees results returned by `fuse_search_strings`, and the ranges borrowed from them. Does nothing if null.
+//
+// # Safety
+// `results` must be null or a pointer returned by `fuse_search_strings`, not already freed.
+void fuse_results_free(struct FuseResults *results);
+
+#endif  /* FUSE_RUST_H */
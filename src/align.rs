@@ -0,0 +1,212 @@
+use crate::{Fuse, Pattern};
+use std::ops::Range;
+
+/// An edit operation of an alignment, turning the pattern into the matched text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditOp {
+    /// the pattern and the text have the same character
+    Match,
+    /// a character of the pattern is replaced by another character in the text
+    Substitute,
+    /// a character of the text is missing from the pattern
+    Insert,
+    /// a character of the pattern is missing from the text
+    Delete,
+}
+
+/// The alignment of a pattern with the text it matched, as found by the Bitap algorithm.
+/// Like the search, alignments work on bytes, so a multibyte character takes one operation per byte.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alignment {
+    /// byte range of the aligned text
+    pub range: Range<usize>,
+    /// number of edit operations other than `Match`
+    pub errors: usize,
+    /// operations of the alignment, in the order of the text
+    pub ops: Vec<EditOp>,
+}
+
+impl Alignment {
+    /// An alignment of nothing, to be filled by `realign`.
+    pub(crate) fn empty() -> Self {
+        Self {
+            range: 0..0,
+            errors: 0,
+            ops: vec![],
+        }
+    }
+
+    /// Aligns a pattern with a prefix of a window of the text, with the fewest edits, see `realign`.
+    pub(crate) fn new(
+        pattern: &[u8],
+        string: &str,
+        window: Range<usize>,
+        is_case_sensitive: bool,
+    ) -> Self {
+        let mut alignment = Self::empty();
+        alignment.realign(pattern, string, window, is_case_sensitive, &mut vec![]);
+        alignment
+    }
+
+    /// Aligns a pattern with a prefix of a window of the text, with the fewest edits,
+    /// reusing the buffer of the previous operations.
+    /// - Parameters:
+    ///   - pattern: The bytes of the pattern, already lowercase unless case sensitive.
+    ///   - string: The whole text.
+    ///   - window: The byte range of the text to align the pattern with, the alignment begins at its start.
+    ///   - costs: Scratch space, reused between calls.
+    pub(crate) fn realign(
+        &mut self,
+        pattern: &[u8],
+        string: &str,
+        window: Range<usize>,
+        is_case_sensitive: bool,
+        costs: &mut Vec<usize>,
+    ) {
+        let text = &string.as_bytes()[window.clone()];
+        let fold = |c: u8| {
+            if is_case_sensitive {
+                c
+            } else {
+                c.to_ascii_lowercase()
+            }
+        };
+        let width = text.len() + 1;
+        let differs = |p: usize, t: usize| usize::from(pattern[p - 1] != fold(text[t - 1]));
+
+        // costs[p * width + t] is the edit distance between the first `p` bytes of the pattern and of the text.
+        costs.clear();
+        costs.resize((pattern.len() + 1) * width, 0);
+        for p in 0..=pattern.len() {
+            for t in 0..width {
+                costs[p * width + t] = if p == 0 {
+                    t
+                } else if t == 0 {
+                    p
+                } else {
+                    let substitution = costs[(p - 1) * width + t - 1] + differs(p, t);
+                    let deletion = costs[(p - 1) * width + t] + 1;
+                    let insertion = costs[p * width + t - 1] + 1;
+                    substitution.min(deletion).min(insertion)
+                };
+            }
+        }
+
+        let last_row = &costs[pattern.len() * width..];
+        let end = (0..width).min_by_key(|&t| last_row[t]).unwrap();
+        let errors = last_row[end];
+
+        let ops = &mut self.ops;
+        ops.clear();
+        let (mut p, mut t) = (pattern.len(), end);
+        while p > 0 || t > 0 {
+            let cost = costs[p * width + t];
+            if p > 0 && t > 0 && cost == costs[(p - 1) * width + t - 1] + differs(p, t) {
+                ops.push(if differs(p, t) == 0 {
+                    EditOp::Match
+                } else {
+                    EditOp::Substitute
+                });
+                (p, t) = (p - 1, t - 1);
+            } else if p > 0 && cost == costs[(p - 1) * width + t] + 1 {
+                ops.push(EditOp::Delete);
+                p -= 1;
+            } else {
+                ops.push(EditOp::Insert);
+                t -= 1;
+            }
+        }
+        ops.reverse();
+
+        self.range = window.start..window.start + end;
+        self.errors = errors;
+    }
+
+    /// Pushes the ranges of the matched characters to `ranges`, skipping the ones shorter than `min_length`.
+    /// Ranges are narrowed to character boundaries, as a multibyte character may only partially match.
+    pub(crate) fn push_matched_ranges(
+        &self,
+        string: &str,
+        min_length: usize,
+        ranges: &mut Vec<Range<usize>>,
+    ) {
+        let mut push = |mut range: Range<usize>| {
+            while !string.is_char_boundary(range.start) {
+                range.start += 1;
+            }
+            while !string.is_char_boundary(range.end) {
+                range.end -= 1;
+            }
+            if range.start < range.end && range.len() >= min_length {
+                ranges.push(range);
+            }
+        };
+
+        let mut position = self.range.start;
+        let mut run = position..position;
+        for op in &self.ops {
+            match op {
+                EditOp::Match => {
+                    if run.is_empty() {
+                        run.start = position;
+                    }
+                    position += 1;
+                    run.end = position;
+                }
+                EditOp::Substitute | EditOp::Insert => {
+                    push(std::mem::replace(&mut run, position..position));
+                    position += 1;
+                }
+                EditOp::Delete => {}
+            }
+        }
+        push(run);
+    }
+}
+
+impl Fuse {
+    /// Aligns a pattern with the text beginning at `location`, matched by Bitap with `errors` edits.
+    pub(crate) fn align_at(
+        &self,
+        pattern: &Pattern,
+        string: &str,
+        location: usize,
+        errors: usize,
+    ) -> Alignment {
+        Alignment::new(
+            pattern.text.as_bytes(),
+            string,
+            Self::align_window(pattern, string, location, errors),
+            self.is_case_sensitive,
+        )
+    }
+
+    /// The window of the text to align a pattern with, beginning at the `location` where Bitap matched it with `errors` edits.
+    pub(crate) fn align_window(
+        pattern: &Pattern,
+        string: &str,
+        location: usize,
+        errors: usize,
+    ) -> Range<usize> {
+        let end = string.len().min(location + pattern.len + errors);
+        location.min(end)..end
+    }
+
+    /// Aligns a pattern with the text it matches in a given string, detailing how each byte matched.
+    /// This is what the `ranges` of a search result are computed from, and is mostly useful to debug a match.
+    /// The whole pattern is aligned, even with `tokenize`, and a custom `matcher` is not used.
+    /// - Parameters:
+    ///   - pattern: The pattern to search for. This is created by calling `createPattern`
+    ///   - string: The string in which to search for the pattern
+    /// - Returns: Some(Alignment) with the aligned `range` and its edit `ops` if the pattern matches, None otherwise.
+    /// # Examples:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// let pattern = fuse.create_pattern("od mn war");
+    /// let alignment = fuse.align(pattern.as_ref(), "Old Man's War");
+    /// ```
+    pub fn align(&self, pattern: Option<&Pattern>, string: &str) -> Option<Alignment> {
+        self.searcher().align(pattern, string)
+    }
+}
@@ -0,0 +1,184 @@
+//! `fuse`, a command-line fuzzy finder built with the `cli` feature.
+//!
+//! Reads lines from a file or stdin, and prints the ones matching a query ranked by score.
+//! Exits with `0` if a line matched, `1` if none did and `2` on errors, like `grep`.
+
+use clap::{Parser, ValueEnum};
+use fuse_rust::{Fuse, FuseBuilder, SearchResult};
+use std::io::{self, IsTerminal, Read, Write};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const HIGHLIGHT: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// Fuzzy searches lines of text, printing the matching ones ranked by score.
+#[derive(Parser)]
+#[command(name = "fuse", version)]
+struct Args {
+    /// the pattern to search for
+    query: String,
+    /// file to read lines from, stdin if omitted or `-`
+    file: Option<PathBuf>,
+    /// score above which lines are not matched, from 0.0 (exact) to 1.0 (anything)
+    #[arg(short, long, default_value_t = 0.6)]
+    threshold: f64,
+    /// how far from the location a match scores as a complete mismatch
+    #[arg(short, long, default_value_t = 100)]
+    distance: i32,
+    /// where in the lines the pattern is expected to be found
+    #[arg(short, long, default_value_t = 0)]
+    location: i32,
+    /// match lowercase and uppercase separately
+    #[arg(short, long)]
+    case_sensitive: bool,
+    /// also match the words of the query on their own
+    #[arg(long)]
+    tokenize: bool,
+    /// print at most this many matches
+    #[arg(short = 'n', long)]
+    limit: Option<usize>,
+    /// print the score of every match before it
+    #[arg(short, long)]
+    scores: bool,
+    /// print one JSON object per match, with its line number, line, score and byte ranges
+    #[arg(long, conflicts_with = "scores")]
+    json: bool,
+    /// when to highlight matched characters
+    #[arg(long, value_enum, default_value_t = Color::Auto)]
+    color: Color,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Color {
+    /// when printing to a terminal, unless `NO_COLOR` is set
+    Auto,
+    /// even when printing to a pipe or a file
+    Always,
+    /// never print escape codes
+    Never,
+}
+
+impl Color {
+    fn enabled(self) -> bool {
+        match self {
+            Color::Auto => io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            Color::Always => true,
+            Color::Never => false,
+        }
+    }
+}
+
+impl Args {
+    fn fuse(&self) -> Result<Fuse, fuse_rust::ConfigError> {
+        Ok(FuseBuilder::new()
+            .threshold(self.threshold)?
+            .distance(self.distance)?
+            .location(self.location)?
+            .is_case_sensitive(self.case_sensitive)
+            .tokenize(self.tokenize)
+            .build())
+    }
+
+    fn input(&self) -> io::Result<String> {
+        let mut bytes = vec![];
+        match &self.file {
+            Some(path) if path.as_os_str() != "-" => bytes = std::fs::read(path)?,
+            _ => {
+                io::stdin().lock().read_to_end(&mut bytes)?;
+            }
+        }
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Wraps the matched ranges of a line in ANSI escape codes.
+/// Tokenized searches return ranges per token, so they are sorted and overlapping or adjacent ones merged first.
+fn highlight(line: &str, ranges: &[Range<usize>]) -> String {
+    let mut sorted: Vec<Range<usize>> = ranges
+        .iter()
+        .filter(|range| !range.is_empty())
+        .cloned()
+        .collect();
+    sorted.sort_unstable_by_key(|range| range.start);
+
+    let mut highlighted = String::with_capacity(line.len());
+    let mut end = 0;
+    let mut sorted = sorted.into_iter().peekable();
+    while let Some(mut range) = sorted.next() {
+        while let Some(next) = sorted.next_if(|next| next.start <= range.end) {
+            range.end = range.end.max(next.end);
+        }
+        highlighted.push_str(&line[end..range.start]);
+        highlighted.push_str(HIGHLIGHT);
+        highlighted.push_str(&line[range.clone()]);
+        highlighted.push_str(RESET);
+        end = range.end;
+    }
+    highlighted.push_str(&line[end..]);
+    highlighted
+}
+
+fn print(args: &Args, lines: &[&str], results: &[SearchResult]) -> io::Result<()> {
+    let color = args.color.enabled();
+    let mut out = io::BufWriter::new(io::stdout().lock());
+    for result in results {
+        let line = lines[result.index];
+        if args.json {
+            let ranges: Vec<[usize; 2]> = result
+                .ranges
+                .iter()
+                .map(|range| [range.start, range.end])
+                .collect();
+            let json = serde_json::json!({
+                "line_number": result.index + 1,
+                "line": line,
+                "score": result.score,
+                "ranges": ranges,
+            });
+            writeln!(out, "{json}")?;
+            continue;
+        }
+        if args.scores {
+            write!(out, "{:.4}\t", result.score)?;
+        }
+        if color {
+            writeln!(out, "{}", highlight(line, &result.ranges))?;
+        } else {
+            writeln!(out, "{line}")?;
+        }
+    }
+    out.flush()
+}
+
+fn run(args: &Args) -> Result<bool, String> {
+    let fuse = args.fuse().map_err(|error| error.to_string())?;
+    let input = args.input().map_err(|error| match &args.file {
+        Some(path) => format!("{}: {error}", path.display()),
+        None => error.to_string(),
+    })?;
+    let lines: Vec<&str> = input.lines().collect();
+
+    let mut results = fuse.search_text_in_iterable(&args.query, &lines);
+    if let Some(limit) = args.limit {
+        results.truncate(limit);
+    }
+    match print(args, &lines, &results) {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => return Err(error.to_string()),
+        _ => {}
+    }
+    Ok(!results.is_empty())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(error) => {
+            eprintln!("fuse: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{DamerauLevenshtein, Fuse, Matcher, TieBreaker};
+use std::fmt;
+use std::sync::Arc;
+
+/// Error returned for an invalid `Fuse` configuration.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// `location` is negative
+    InvalidLocation(i32),
+    /// `distance` is negative
+    InvalidDistance(i32),
+    /// `threshold` is not between `0.0` and `1.0`
+    InvalidThreshold(f64),
+    /// `min_match_char_length` is `0`
+    InvalidMinMatchCharLength(usize),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLocation(value) => {
+                write!(f, "Invalid Location: {} is negative.", value)
+            }
+            Self::InvalidDistance(value) => {
+                write!(f, "Invalid Distance: {} is negative.", value)
+            }
+            Self::InvalidThreshold(value) => {
+                write!(
+                    f,
+                    "Invalid Threshold: {} is not between 0.0 and 1.0.",
+                    value
+                )
+            }
+            Self::InvalidMinMatchCharLength(value) => write!(
+                f,
+                "Invalid Min Match Char Length: {} is not at least 1.",
+                value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Builds a `Fuse` instance, validating every setting as it is set.
+/// Presets provide a starting point for common use cases, and can be further customized.
+/// A `Fuse` can also be built from its fields, setting the remaining ones with `..Default::default()`,
+/// use `Fuse::validate` to check such a config.
+/// # Examples:
+/// Basic Usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse, FuseBuilder, ConfigError };
+/// # fn main() -> Result<(), ConfigError> {
+/// let fuse = Fuse::builder()
+///     .threshold(0.3)?
+///     .distance(50)?
+///     .tokenize(true)
+///     .build();
+///
+/// let fuse = FuseBuilder::autocomplete().is_case_sensitive(true).build();
+/// # Ok(())
+/// # }
+/// ```
+pub struct FuseBuilder {
+    fuse: Fuse,
+}
+
+impl Default for FuseBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuseBuilder {
+    /// Creates a builder with the default config, see `Fuse::default`.
+    pub fn new() -> Self {
+        Self {
+            fuse: Fuse::default(),
+        }
+    }
+
+    /// Preset for precise searches, only matching strings with very few errors close to the expected location.
+    pub fn strict() -> Self {
+        Self {
+            fuse: Fuse {
+                threshold: 0.2,
+                distance: 50,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Preset for user typed queries, tolerating several typos anywhere in the string.
+    /// Uses the `DamerauLevenshtein` matcher, so swapped letters count as a single typo,
+    /// and tokenizes the query so every word is matched on its own.
+    pub fn typo_tolerant() -> Self {
+        Self {
+            fuse: Fuse {
+                threshold: 0.4,
+                tokenize: true,
+                matcher: Some(Arc::new(DamerauLevenshtein)),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Preset for searching as the user types, favoring matches at the start of strings.
+    pub fn autocomplete() -> Self {
+        Self {
+            fuse: Fuse {
+                location: 0,
+                distance: 20,
+                threshold: 0.4,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sets where in the string the pattern is expected to be found.
+    /// - Returns: `ConfigError::InvalidLocation` if the location is negative.
+    pub fn location(mut self, location: i32) -> Result<Self, ConfigError> {
+        if location < 0 {
+            return Err(ConfigError::InvalidLocation(location));
+        }
+        self.fuse.location = location;
+        Ok(self)
+    }
+
+    /// Sets how far from the location a match scores as a complete mismatch.
+    /// - Returns: `ConfigError::InvalidDistance` if the distance is negative.
+    pub fn distance(mut self, distance: i32) -> Result<Self, ConfigError> {
+        if distance < 0 {
+            return Err(ConfigError::InvalidDistance(distance));
+        }
+        self.fuse.distance = distance;
+        Ok(self)
+    }
+
+    /// Sets the score above which the search gives up, `0.0` requires a perfect match and `1.0` matches anything.
+    /// - Returns: `ConfigError::InvalidThreshold` if the threshold is not between `0.0` and `1.0`.
+    pub fn threshold(mut self, threshold: f64) -> Result<Self, ConfigError> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(ConfigError::InvalidThreshold(threshold));
+        }
+        self.fuse.threshold = threshold;
+        Ok(self)
+    }
+
+    /// Sets the minimum length of a matched range.
+    /// - Returns: `ConfigError::InvalidMinMatchCharLength` if the length is `0`.
+    pub fn min_match_char_length(
+        mut self,
+        min_match_char_length: usize,
+    ) -> Result<Self, ConfigError> {
+        if min_match_char_length == 0 {
+            return Err(ConfigError::InvalidMinMatchCharLength(
+                min_match_char_length,
+            ));
+        }
+        self.fuse.min_match_char_length = min_match_char_length;
+        Ok(self)
+    }
+
+    /// Sets whether lowercase and uppercase are matched separately.
+    pub fn is_case_sensitive(mut self, is_case_sensitive: bool) -> Self {
+        self.fuse.is_case_sensitive = is_case_sensitive;
+        self
+    }
+
+    /// Sets whether the words of the search patterns are also matched on their own.
+    pub fn tokenize(mut self, tokenize: bool) -> Self {
+        self.fuse.tokenize = tokenize;
+        self
+    }
+
+    /// Sets whether to keep scanning the string after a perfect match, and highlight every occurrence.
+    pub fn find_all_matches(mut self, find_all_matches: bool) -> Self {
+        self.fuse.find_all_matches = find_all_matches;
+        self
+    }
+
+    /// Sets the algorithm used to score strings.
+    pub fn matcher(mut self, matcher: impl Matcher + 'static) -> Self {
+        self.fuse.matcher = Some(Arc::new(matcher));
+        self
+    }
+
+    /// Sets whether results are sorted by score, or keep the order of the list.
+    pub fn should_sort(mut self, should_sort: bool) -> Self {
+        self.fuse.should_sort = should_sort;
+        self
+    }
+
+    /// Sets how results with the same score are ordered.
+    pub fn tie_breaker(mut self, tie_breaker: TieBreaker) -> Self {
+        self.fuse.tie_breaker = tie_breaker;
+        self
+    }
+
+    /// Creates the `Fuse` instance.
+    pub fn build(self) -> Fuse {
+        self.fuse
+    }
+}
+
+impl Fuse {
+    /// Creates a builder with the default config, see `FuseBuilder`.
+    pub fn builder() -> FuseBuilder {
+        FuseBuilder::new()
+    }
+
+    /// Checks every setting of the config, for instances created from their fields rather than with `FuseBuilder`.
+    /// - Returns: The first `ConfigError` found, if any.
+    /// # Examples:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse {
+    ///     threshold: 1.5,
+    ///     ..Default::default()
+    /// };
+    /// assert!(fuse.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        FuseBuilder::new()
+            .location(self.location)?
+            .distance(self.distance)?
+            .threshold(self.threshold)?
+            .min_match_char_length(self.min_match_char_length)?;
+        Ok(())
+    }
+}
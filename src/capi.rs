@@ -0,0 +1,268 @@
+//! C API, enabled by the `capi` feature.
+//!
+//! Build the shared library with `cargo rustc --release --features capi --crate-type cdylib`, or a static one with
+//! `--crate-type staticlib`. The declarations are in `include/fuse_rust.h`, generated with `cbindgen --quiet --output include/fuse_rust.h`.
+//!
+//! No panic unwinds into C: a function that panics returns `FUSE_STATUS_PANIC`, or null, `0` or nothing.
+//!
+//! Ownership is explicit: a `Fuse` created by `fuse_new` is freed by `fuse_free`, and `FuseResults` returned by
+//! `fuse_search_strings` are freed by `fuse_results_free`. A `FuseResult` and its `ranges` are borrowed from their
+//! `FuseResults` and are only valid until it is freed. Strings passed to the API are borrowed for the duration of
+//! the call only.
+
+use crate::{Fuse, SearchResult};
+use std::ffi::{c_char, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Status returned by the functions of the C API.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuseStatus {
+    /// the call succeeded
+    Ok = 0,
+    /// a required pointer argument was null
+    NullPointer = 1,
+    /// a string argument was not valid UTF-8
+    InvalidUtf8 = 2,
+    /// a config value was invalid, the config is unchanged
+    InvalidConfig = 3,
+    /// an index was out of bounds
+    OutOfBounds = 4,
+    /// the call panicked, a search returns no results and a config is left unchanged
+    Panic = 5,
+}
+
+/// Byte range of matched characters in a searched string.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuseRange {
+    /// byte offset of the first matched byte
+    pub start: usize,
+    /// byte offset after the last matched byte
+    pub end: usize,
+}
+
+/// A search result, borrowed from the `FuseResults` it was read from.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FuseResult {
+    /// index of the string in the searched list
+    pub index: usize,
+    /// score of the string, 0.0 is a perfect match 1.0 is a perfect mismatch
+    pub score: f64,
+    /// matched ranges, valid until the `FuseResults` are freed
+    pub ranges: *const FuseRange,
+    /// number of matched ranges
+    pub ranges_len: usize,
+}
+
+/// The results of a search, sorted by score, freed with `fuse_results_free`.
+pub struct FuseResults {
+    results: Vec<SearchResult>,
+    ranges: Vec<Vec<FuseRange>>,
+}
+
+/// Runs the body of an exported function, returning `fallback` if it panics instead of unwinding into C.
+fn guard<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+/// A fuse instance, created with `fuse_new` and freed with `fuse_free`.
+pub struct FuseHandle(Fuse);
+
+/// Creates a fuse instance with the default config, to be freed with `fuse_free`. Returns null if it fails.
+#[no_mangle]
+pub extern "C" fn fuse_new() -> *mut FuseHandle {
+    guard(std::ptr::null_mut(), || {
+        Box::into_raw(Box::new(FuseHandle(Fuse::default())))
+    })
+}
+
+/// Frees a fuse instance created by `fuse_new`. Does nothing if `fuse` is null.
+///
+/// # Safety
+/// `fuse` must be null or a pointer returned by `fuse_new`, not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_free(fuse: *mut FuseHandle) {
+    if !fuse.is_null() {
+        guard((), || drop(Box::from_raw(fuse)));
+    }
+}
+
+/// Sets a config field, keeping the previous value if the new one is invalid.
+unsafe fn set<T>(
+    fuse: *mut FuseHandle,
+    value: T,
+    field: impl Fn(&mut Fuse) -> &mut T,
+) -> FuseStatus {
+    let Some(FuseHandle(fuse)) = fuse.as_mut() else {
+        return FuseStatus::NullPointer;
+    };
+    guard(FuseStatus::Panic, || {
+        let previous = std::mem::replace(field(fuse), value);
+        if fuse.validate().is_err() {
+            *field(fuse) = previous;
+            return FuseStatus::InvalidConfig;
+        }
+        FuseStatus::Ok
+    })
+}
+
+/// Sets the score above which the search gives up, between `0.0` and `1.0`.
+///
+/// # Safety
+/// `fuse` must be null or a valid pointer returned by `fuse_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_set_threshold(fuse: *mut FuseHandle, threshold: f64) -> FuseStatus {
+    set(fuse, threshold, |fuse| &mut fuse.threshold)
+}
+
+/// Sets where in the string the pattern is expected to be found, at least `0`.
+///
+/// # Safety
+/// `fuse` must be null or a valid pointer returned by `fuse_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_set_location(fuse: *mut FuseHandle, location: i32) -> FuseStatus {
+    set(fuse, location, |fuse| &mut fuse.location)
+}
+
+/// Sets how far from the location a match scores as a complete mismatch, at least `0`.
+///
+/// # Safety
+/// `fuse` must be null or a valid pointer returned by `fuse_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_set_distance(fuse: *mut FuseHandle, distance: i32) -> FuseStatus {
+    set(fuse, distance, |fuse| &mut fuse.distance)
+}
+
+/// Sets whether lowercase and uppercase are matched separately.
+///
+/// # Safety
+/// `fuse` must be null or a valid pointer returned by `fuse_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_set_is_case_sensitive(
+    fuse: *mut FuseHandle,
+    is_case_sensitive: bool,
+) -> FuseStatus {
+    set(fuse, is_case_sensitive, |fuse| &mut fuse.is_case_sensitive)
+}
+
+/// Sets whether the words of the search patterns are also matched on their own.
+///
+/// # Safety
+/// `fuse` must be null or a valid pointer returned by `fuse_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_set_tokenize(fuse: *mut FuseHandle, tokenize: bool) -> FuseStatus {
+    set(fuse, tokenize, |fuse| &mut fuse.tokenize)
+}
+
+/// Searches for a text pattern in a list of strings, see `Fuse::search_text_in_iterable`.
+/// On success, `*out` is set to the results, to be freed with `fuse_results_free`, otherwise it is left untouched.
+///
+/// # Safety
+/// `fuse` must be a valid pointer returned by `fuse_new`, `text` a nul terminated string,
+/// `list` an array of `len` nul terminated strings, or null if `len` is `0`, and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_search_strings(
+    fuse: *const FuseHandle,
+    text: *const c_char,
+    list: *const *const c_char,
+    len: usize,
+    out: *mut *mut FuseResults,
+) -> FuseStatus {
+    guard(FuseStatus::Panic, || {
+        let (Some(FuseHandle(fuse)), false, false, false) = (
+            fuse.as_ref(),
+            text.is_null(),
+            list.is_null() && len > 0,
+            out.is_null(),
+        ) else {
+            return FuseStatus::NullPointer;
+        };
+        let Ok(text) = CStr::from_ptr(text).to_str() else {
+            return FuseStatus::InvalidUtf8;
+        };
+        let list = if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(list, len)
+        };
+        let mut strings = Vec::with_capacity(len);
+        for &string in list {
+            if string.is_null() {
+                return FuseStatus::NullPointer;
+            }
+            let Ok(string) = CStr::from_ptr(string).to_str() else {
+                return FuseStatus::InvalidUtf8;
+            };
+            strings.push(string);
+        }
+
+        let results = fuse.search_text_in_iterable(text, &strings);
+        let ranges = results
+            .iter()
+            .map(|result| {
+                result
+                    .ranges
+                    .iter()
+                    .map(|range| FuseRange {
+                        start: range.start,
+                        end: range.end,
+                    })
+                    .collect()
+            })
+            .collect();
+        *out = Box::into_raw(Box::new(FuseResults { results, ranges }));
+        FuseStatus::Ok
+    })
+}
+
+/// Returns the number of results, `0` if `results` is null.
+///
+/// # Safety
+/// `results` must be null or a valid pointer returned by `fuse_search_strings`.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_results_len(results: *const FuseResults) -> usize {
+    guard(0, || {
+        results.as_ref().map_or(0, |results| results.results.len())
+    })
+}
+
+/// Reads the result at `index` into `*out`. Its `ranges` are valid until `results` is freed.
+///
+/// # Safety
+/// `results` must be null or a valid pointer returned by `fuse_search_strings`, and `out` null or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_results_get(
+    results: *const FuseResults,
+    index: usize,
+    out: *mut FuseResult,
+) -> FuseStatus {
+    guard(FuseStatus::Panic, || {
+        let (Some(results), Some(out)) = (results.as_ref(), out.as_mut()) else {
+            return FuseStatus::NullPointer;
+        };
+        let (Some(result), Some(ranges)) = (results.results.get(index), results.ranges.get(index))
+        else {
+            return FuseStatus::OutOfBounds;
+        };
+        *out = FuseResult {
+            index: result.index,
+            score: result.score,
+            ranges: ranges.as_ptr(),
+            ranges_len: ranges.len(),
+        };
+        FuseStatus::Ok
+    })
+}
+
+/// Frees results returned by `fuse_search_strings`, and the ranges borrowed from them. Does nothing if null.
+///
+/// # Safety
+/// `results` must be null or a pointer returned by `fuse_search_strings`, not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn fuse_results_free(results: *mut FuseResults) {
+    if !results.is_null() {
+        guard((), || drop(Box::from_raw(results)));
+    }
+}
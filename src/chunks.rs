@@ -0,0 +1,97 @@
+use crate::{Fuse, Searcher};
+
+/// Smallest chunk picked by the adaptive chunk size, below which handing a chunk to a thread costs more than
+/// searching it.
+const MIN_CHUNK_SIZE: usize = 32;
+/// Number of chunks per thread picked by the adaptive chunk size, so that threads which finish early take over
+/// the remaining chunks instead of waiting for the slowest one.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// The size of the chunks a list is split into for a parallel search.
+/// - Parameters:
+///   - chunk_size: The size requested by the caller, `0` is treated as `1`. If None, the list is split into a few
+///     chunks per thread, each at least `MIN_CHUNK_SIZE` items long.
+///   - len: The length of the list
+///   - threads: The number of threads searching the list
+fn resolve_chunk_size(chunk_size: Option<usize>, len: usize, threads: usize) -> usize {
+    match chunk_size {
+        Some(chunk_size) => chunk_size.max(1),
+        None => len
+            .div_ceil(threads.max(1) * CHUNKS_PER_THREAD)
+            .max(MIN_CHUNK_SIZE),
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Fuse {
+    /// Searches the chunks of a list on the rayon thread pool, whose threads steal chunks from each other.
+    /// `search` is given a searcher, the offset of a chunk in the list, the chunk and the results to push to.
+    /// - Returns: The results of every chunk, in the order of the list.
+    pub(crate) fn search_chunks_rayon<T: Sync, R: Send>(
+        &self,
+        list: &[T],
+        chunk_size: Option<usize>,
+        search: impl Fn(&mut Searcher, usize, &[T], &mut Vec<R>) + Sync,
+    ) -> Vec<R> {
+        use rayon::prelude::*;
+
+        let chunk_size = resolve_chunk_size(chunk_size, list.len(), rayon::current_num_threads());
+        list.par_chunks(chunk_size)
+            .enumerate()
+            .map_init(
+                || self.searcher(),
+                |searcher, (chunk_index, chunk)| {
+                    let mut items = vec![];
+                    search(searcher, chunk_index * chunk_size, chunk, &mut items);
+                    items
+                },
+            )
+            .flatten_iter()
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Fuse {
+    /// Searches the chunks of a list with a pool of scoped threads, one per available core at most.
+    /// Every thread takes the next chunk not yet searched until there are none left.
+    /// `search` is given a searcher, the offset of a chunk in the list, the chunk and the results to push to.
+    /// - Returns: The results of every chunk, in no particular order.
+    pub(crate) fn search_chunks_threads<T: Sync, R: Send>(
+        &self,
+        list: &[T],
+        chunk_size: Option<usize>,
+        search: impl Fn(&mut Searcher, usize, &[T], &mut Vec<R>) + Sync,
+    ) -> Vec<R> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Mutex;
+
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chunk_size = resolve_chunk_size(chunk_size, list.len(), threads);
+        let chunks = list.len().div_ceil(chunk_size);
+        let next_chunk = AtomicUsize::new(0);
+        let items = Mutex::new(vec![]);
+
+        crossbeam_utils::thread::scope(|scope| {
+            for _ in 0..threads.min(chunks) {
+                scope.spawn(|_| {
+                    let mut searcher = self.searcher();
+                    let mut thread_items = vec![];
+                    loop {
+                        let chunk_index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                        if chunk_index >= chunks {
+                            break;
+                        }
+                        let offset = chunk_index * chunk_size;
+                        let chunk = &list[offset..list.len().min(offset + chunk_size)];
+                        search(&mut searcher, offset, chunk, &mut thread_items);
+                    }
+                    items.lock().unwrap().append(&mut thread_items);
+                });
+            }
+        })
+        .unwrap();
+
+        items.into_inner().unwrap()
+    }
+}
@@ -0,0 +1,286 @@
+use crate::index::{ngrams, NgramIndex};
+use crate::{Fuse, Levenshtein, Matcher, Pattern, Searcher};
+use std::collections::HashMap;
+
+/// Longest pattern the Bitap algorithm can search, longer items are compared with the `Levenshtein` matcher.
+const MAX_BITAP_LENGTH: usize = u64::BITS as usize;
+
+/// Two items of a cluster matching each other.
+#[derive(Debug, PartialEq)]
+pub struct ClusterPair {
+    /// index of the first item in the original list
+    pub first: usize,
+    /// index of the second item in the original list, always greater than `first`
+    pub second: usize,
+    /// highest of the scores of searching either item in the other
+    pub score: f64,
+}
+
+/// A group of near duplicate items, returned by `Fuse::cluster`.
+#[derive(Debug, PartialEq)]
+pub struct Cluster {
+    /// index of the item standing for the whole cluster
+    pub representative: usize,
+    /// indices of every item in the cluster, in ascending order
+    pub members: Vec<usize>,
+    /// every pair of items in the cluster matching each other
+    pub pairs: Vec<ClusterPair>,
+}
+
+/// Disjoint sets of items, merged as matching pairs are found.
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut item: usize) -> usize {
+        while self.parents[item] != item {
+            self.parents[item] = self.parents[self.parents[item]];
+            item = self.parents[item];
+        }
+        item
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        let (root, child) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+    }
+}
+
+impl Fuse {
+    /// Pushes to `candidates` the items following `item` that can possibly match it,
+    /// the ones sharing enough n-grams with it like in `NgramIndex`.
+    /// - Parameters:
+    ///   - counts, touched: Scratch space, reused between calls.
+    fn cluster_candidates(
+        &self,
+        index: &NgramIndex,
+        patterns: &[Option<Pattern>],
+        item: usize,
+        counts: &mut [u32],
+        touched: &mut Vec<usize>,
+        candidates: &mut Vec<usize>,
+    ) {
+        candidates.clear();
+        let Some(pattern) = &patterns[item] else {
+            return;
+        };
+
+        // The error bound is only known for Bitap and Levenshtein, and a non ascii character
+        // can take several bytes, destroying more n-grams per error.
+        if self.matcher.is_some() || !pattern.text.is_ascii() {
+            candidates.extend(item + 1..patterns.len());
+            return;
+        }
+
+        let n = index.ngram_size();
+        touched.clear();
+        for pattern in std::iter::once(pattern).chain(pattern.words.iter().flatten()) {
+            let required = index.required_ngrams(self, pattern);
+            if required <= 0 {
+                candidates.clear();
+                candidates.extend(item + 1..patterns.len());
+                return;
+            }
+
+            let mut grams: HashMap<u32, u32> = HashMap::new();
+            for gram in ngrams(pattern.text.as_bytes(), n) {
+                *grams.entry(gram).or_default() += 1;
+            }
+            for (gram, count) in grams {
+                let postings = index.postings(gram);
+                for &other in &postings[postings.partition_point(|&other| other <= item)..] {
+                    if counts[other] == 0 {
+                        touched.push(other);
+                    }
+                    counts[other] += count;
+                }
+            }
+            for other in touched.drain(..) {
+                if counts[other] as i64 >= required {
+                    candidates.push(other);
+                }
+                counts[other] = 0;
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+    }
+
+    /// Scores a pair of items as the highest score of searching either one in the other.
+    fn pair_score(
+        &self,
+        searcher: &mut Searcher,
+        patterns: &[Option<Pattern>],
+        list: &[impl AsRef<str>],
+        first: usize,
+        second: usize,
+    ) -> Option<f64> {
+        let (Some(a), Some(b)) = (&patterns[first], &patterns[second]) else {
+            return None;
+        };
+        let (x, y) = (list[first].as_ref(), list[second].as_ref());
+        let score = if self.matcher.is_none() && a.len.max(b.len) > MAX_BITAP_LENGTH {
+            let forward = Levenshtein.score_only(self, a, y);
+            forward.max(Levenshtein.score_only(self, b, x))
+        } else {
+            let forward = searcher.score(Some(a), y)?;
+            forward.max(searcher.score(Some(b), x)?)
+        };
+        (score <= self.threshold).then_some(score)
+    }
+
+    /// Groups the near duplicates of a list of strings.
+    /// Two items match when searching either one in the other scores under the `threshold`,
+    /// and clusters are formed by all the items linked by matching pairs.
+    /// Rather than comparing every pair, an n-gram index is used to only compare the items sharing
+    /// enough n-grams to possibly match, which is most effective with low thresholds.
+    /// Items longer than 64 bytes, which the Bitap algorithm can't search, are compared with the `Levenshtein` matcher.
+    ///
+    /// - Parameters:
+    ///   - list: The list of strings to group
+    /// - Returns: Vec<Cluster> with every cluster of at least two items, ordered by their first member.
+    ///   The representative of a cluster is the member matching the most other members, with the lowest total score.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse {
+    ///     threshold: 0.2,
+    ///     ..Default::default()
+    /// };
+    /// let products = [
+    ///     "Apple iPhone 15 Pro",
+    ///     "Apple iPhone 15 Pro ",
+    ///     "Samsung Galaxy S24",
+    ///     "Aple iPhone 15 Pro",
+    /// ];
+    ///
+    /// let clusters = fuse.cluster(&products);
+    /// ```
+    pub fn cluster<S: AsRef<str>>(&self, list: &[S]) -> Vec<Cluster> {
+        let patterns: Vec<Option<Pattern>> = list
+            .iter()
+            .map(|item| self.create_pattern(item.as_ref()))
+            .collect();
+        let index = self.create_index(list);
+        let mut searcher = self.searcher();
+        let mut sets = UnionFind::new(list.len());
+        let mut pairs = vec![];
+
+        let mut counts = vec![0; list.len()];
+        let mut touched = vec![];
+        let mut candidates = vec![];
+        for first in 0..list.len() {
+            self.cluster_candidates(
+                &index,
+                &patterns,
+                first,
+                &mut counts,
+                &mut touched,
+                &mut candidates,
+            );
+            for &second in &candidates {
+                if let Some(score) = self.pair_score(&mut searcher, &patterns, list, first, second)
+                {
+                    sets.union(first, second);
+                    pairs.push(ClusterPair {
+                        first,
+                        second,
+                        score,
+                    });
+                }
+            }
+        }
+
+        let mut clusters: Vec<Cluster> = vec![];
+        let mut roots: HashMap<usize, usize> = HashMap::new();
+        for pair in pairs {
+            let cluster = *roots.entry(sets.find(pair.first)).or_insert_with(|| {
+                clusters.push(Cluster {
+                    representative: pair.first,
+                    members: vec![],
+                    pairs: vec![],
+                });
+                clusters.len() - 1
+            });
+            clusters[cluster].pairs.push(pair);
+        }
+        for item in 0..list.len() {
+            if let Some(&cluster) = roots.get(&sets.find(item)) {
+                clusters[cluster].members.push(item);
+            }
+        }
+
+        for cluster in &mut clusters {
+            let mut stats: HashMap<usize, (usize, f64)> = HashMap::new();
+            for pair in &cluster.pairs {
+                for member in [pair.first, pair.second] {
+                    let (count, total) = stats.entry(member).or_default();
+                    *count += 1;
+                    *total += pair.score;
+                }
+            }
+            cluster.representative = cluster
+                .members
+                .iter()
+                .copied()
+                .min_by(|a, b| {
+                    let (a_count, a_total) = stats[a];
+                    let (b_count, b_total) = stats[b];
+                    b_count
+                        .cmp(&a_count)
+                        .then(a_total.partial_cmp(&b_total).unwrap())
+                        .then(a.cmp(b))
+                })
+                .unwrap();
+        }
+        clusters.sort_unstable_by_key(|cluster| cluster.members[0]);
+        clusters
+    }
+
+    /// Removes the near duplicates of a list of strings, keeping only the representative of every cluster,
+    /// see `cluster` for how items are grouped.
+    ///
+    /// - Parameters:
+    ///   - list: The list of strings to deduplicate
+    /// - Returns: The indices of the items to keep, in ascending order.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse {
+    ///     threshold: 0.2,
+    ///     ..Default::default()
+    /// };
+    /// let products = ["Apple iPhone 15 Pro", "Aple iPhone 15 Pro", "Samsung Galaxy S24"];
+    ///
+    /// let unique = fuse.dedup(&products);
+    /// ```
+    pub fn dedup<S: AsRef<str>>(&self, list: &[S]) -> Vec<usize> {
+        let mut duplicates = vec![false; list.len()];
+        for cluster in self.cluster(list) {
+            for member in cluster.members {
+                duplicates[member] = member != cluster.representative;
+            }
+        }
+        (0..list.len()).filter(|&item| !duplicates[item]).collect()
+    }
+}
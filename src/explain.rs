@@ -0,0 +1,236 @@
+use crate::{utils, Fuse, FuseableExt, Pattern, Searcher};
+use std::fmt;
+use std::ops::Range;
+
+/// Where the Bitap algorithm matched a pattern, and how its score was computed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitapMatch {
+    /// byte location in the string where the match begins
+    pub location: usize,
+    /// number of insertions, deletions and substitutions of the match
+    pub errors: usize,
+    /// part of the score due to errors, the number of errors divided by the pattern length
+    pub accuracy: f64,
+    /// part of the score due to the distance between the match and the expected `location`, scaled by `distance`
+    pub proximity: f64,
+}
+
+/// Breakdown of the score of a single pattern, the whole query or one of its tokens, against a string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermExplanation {
+    /// text of the pattern, lowercased unless the search is case sensitive
+    pub pattern: String,
+    /// score of the pattern, `1.0` if it didn't match
+    pub score: f64,
+    /// the Bitap match the score was computed from, None if there was no match or a custom `matcher` was used
+    pub matched: Option<BitapMatch>,
+    /// ranges of the matched characters
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Breakdown of the score of a query against a string, returned by `Fuse::explain`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation {
+    /// final score, the average score of the terms, None if the string isn't a match
+    pub score: Option<f64>,
+    /// the whole query first, followed by its tokens when `tokenize` is enabled
+    pub terms: Vec<TermExplanation>,
+}
+
+/// Breakdown of the score of a query against a single property of a `Fuseable` item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyExplanation {
+    /// name of the property
+    pub key: String,
+    /// weight of the property, as given by `properties`
+    pub weight: f64,
+    /// factor the score of the property is multiplied by, derived from its weight
+    pub factor: f64,
+    /// weighted score of the property, None if it isn't a match
+    pub score: Option<f64>,
+    /// breakdown of the score of the property value
+    pub explanation: Explanation,
+}
+
+/// Breakdown of the score of a query against a `FuseableExt` item, returned by `Fuse::explain_fuseable`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuseableExplanation {
+    /// final score, the average weighted score of the matched properties, None if no property matched
+    pub score: Option<f64>,
+    /// every property of the item, in the order of `properties`
+    pub keys: Vec<KeyExplanation>,
+}
+
+impl Searcher<'_> {
+    fn explain_util(&mut self, pattern: Option<&Pattern>, string: &str) -> Explanation {
+        let Some(pattern) = pattern else {
+            return Explanation {
+                score: None,
+                terms: vec![],
+            };
+        };
+        let terms: Vec<TermExplanation> = std::iter::once(pattern)
+            .chain(pattern.words.iter().flatten())
+            .map(|pattern| self.explain_term(pattern, string))
+            .collect();
+
+        let score = terms.iter().map(|term| term.score).sum::<f64>() / terms.len() as f64;
+        Explanation {
+            score: ((score - 1.0).abs() >= 0.00001).then_some(score),
+            terms,
+        }
+    }
+}
+
+impl Fuse {
+    /// Creates the breakdown of a Bitap match, as computed by `utils::calculate_score`.
+    pub(crate) fn bitap_match(
+        &self,
+        pattern: &Pattern,
+        location: usize,
+        errors: usize,
+    ) -> BitapMatch {
+        let accuracy = errors as f64 / pattern.len as f64;
+        let proximity = utils::calculate_score(
+            pattern.len,
+            0,
+            location as i32,
+            self.location,
+            self.distance,
+        );
+        BitapMatch {
+            location,
+            errors,
+            accuracy,
+            proximity,
+        }
+    }
+
+    /// Explains how the score of a query against a string is computed, to understand why a string ranks above another.
+    /// The score is the same as the one of `search_text_in_string`.
+    /// - Parameters:
+    ///   - query: The pattern string to search for
+    ///   - item: The string in which to search for the pattern
+    /// - Returns: An `Explanation` with the final score and the breakdown of every term, which can be printed with `Display`.
+    /// # Examples:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// println!("{}", fuse.explain("od mn war", "Old Man's War"));
+    /// ```
+    pub fn explain(&self, query: &str, item: &str) -> Explanation {
+        let pattern = self.create_pattern(query);
+        self.searcher().explain_util(pattern.as_ref(), item)
+    }
+
+    /// Explains how the score of a query against a `FuseableExt` item is computed, see `explain`.
+    /// The score is the same as the one of `search_text_in_fuse_list`.
+    /// - Parameters:
+    ///   - query: The pattern string to search for
+    ///   - item: A `FuseableExt` object, such as any `Fuseable` object, in which to search for the pattern
+    /// - Returns: A `FuseableExplanation` with the final score and the breakdown of every property.
+    pub fn explain_fuseable(&self, query: &str, item: &impl FuseableExt) -> FuseableExplanation {
+        let pattern = self.create_pattern(query);
+        let mut searcher = self.searcher();
+
+        let keys: Vec<KeyExplanation> = item
+            .fields()
+            .iter()
+            .map(|field| {
+                // Like the search, a field with several values is explained by its best matching value.
+                let explanation = Self::field_values(item, field)
+                    .as_slice()
+                    .iter()
+                    .map(|value| searcher.explain_util(pattern.as_ref(), value))
+                    .min_by(|a, b| {
+                        let (a, b) = (a.score.unwrap_or(1.0), b.score.unwrap_or(1.0));
+                        a.partial_cmp(&b).unwrap()
+                    })
+                    .unwrap_or(Explanation {
+                        score: None,
+                        terms: vec![],
+                    });
+                let (factor, _) = utils::weigh_score(1.0, field.weight);
+                KeyExplanation {
+                    score: explanation
+                        .score
+                        .map(|score| utils::weigh_score(score, field.weight).1),
+                    key: field.name.to_string(),
+                    weight: field.weight,
+                    factor,
+                    explanation,
+                }
+            })
+            .collect();
+
+        let scores: Vec<f64> = keys.iter().filter_map(|key| key.score).collect();
+        FuseableExplanation {
+            score: (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64),
+            keys,
+        }
+    }
+}
+
+impl fmt::Display for TermExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: score {:.4}", self.pattern, self.score)?;
+        if let Some(matched) = &self.matched {
+            write!(
+                f,
+                ", {} error(s) at byte {} = accuracy {:.4} + proximity {:.4}",
+                matched.errors, matched.location, matched.accuracy, matched.proximity
+            )?;
+        }
+        write!(f, ", ranges {:?}", self.ranges)
+    }
+}
+
+impl Explanation {
+    fn write_indented(&self, f: &mut fmt::Formatter<'_>, indent: &str) -> fmt::Result {
+        match self.score {
+            Some(score) => write!(f, "{}score {:.4}", indent, score)?,
+            None => write!(f, "{}no match", indent)?,
+        }
+        write!(f, ", average of {} term(s)", self.terms.len())?;
+        for term in &self.terms {
+            write!(f, "\n{}  {}", indent, term)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, "")
+    }
+}
+
+impl fmt::Display for FuseableExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let matched = self.keys.iter().filter(|key| key.score.is_some()).count();
+        match self.score {
+            Some(score) => write!(f, "score {:.4}", score)?,
+            None => write!(f, "no match")?,
+        }
+        write!(
+            f,
+            ", average of {} matched key(s) out of {}",
+            matched,
+            self.keys.len()
+        )?;
+        for key in &self.keys {
+            write!(
+                f,
+                "\n  key {:?}, weight {} (factor {:.4}): ",
+                key.key, key.weight, key.factor
+            )?;
+            match key.score {
+                Some(score) => write!(f, "weighted score {:.4}", score)?,
+                None => write!(f, "no match")?,
+            }
+            writeln!(f)?;
+            key.explanation.write_indented(f, "    ")?;
+        }
+        Ok(())
+    }
+}
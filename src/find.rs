@@ -0,0 +1,130 @@
+use crate::{Fuse, Pattern};
+use std::cmp::Reverse;
+use std::ops::Range;
+
+/// An approximate occurrence of a pattern in a text, returned by `Fuse::find_all`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Occurrence {
+    /// byte range of the occurrence in the text
+    pub range: Range<usize>,
+    /// number of insertions, deletions and substitutions between the pattern and the occurrence
+    pub errors: usize,
+}
+
+impl Fuse {
+    /// Finds every non overlapping approximate occurrence of a pattern in a text.
+    /// Occurrences are found left to right, each within the number of errors allowed by the `threshold`
+    /// and ending where it has the fewest errors. Of overlapping occurrences the one with the fewest errors is kept,
+    /// and the search resumes after the end of every occurrence.
+    /// The text is scanned with an edit distance table the size of the pattern, so it's suitable for long documents.
+    /// - Parameters:
+    ///   - pattern: The pattern to search for. This is created by calling `createPattern`
+    ///   - text: The text in which to search for the pattern
+    /// - Returns: Vec<Occurrence> in the order of the text, with their byte `range` widened to character boundaries.
+    /// # Examples:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// let pattern = fuse.create_pattern("quick");
+    /// let occurrences = fuse.find_all(pattern.as_ref(), "The quick brown fox, the quikc brown dog");
+    /// ```
+    pub fn find_all(&self, pattern: Option<&Pattern>, text: &str) -> Vec<Occurrence> {
+        let Some(pattern) = pattern else {
+            return vec![];
+        };
+        let needle = pattern.text.as_bytes();
+        let max_errors = pattern.max_errors(self);
+        let bytes = text.as_bytes();
+
+        // costs[i] is the lowest number of errors of matching the first `i` bytes of the pattern up to the current
+        // position of the text, for an occurrence beginning at starts[i].
+        let mut costs: Vec<usize> = (0..=needle.len()).collect();
+        let mut starts = vec![0; needle.len() + 1];
+        let mut occurrences: Vec<Occurrence> = vec![];
+        let mut best: Option<Occurrence> = None;
+
+        let mut position = 0;
+        while position < bytes.len() || best.is_some() {
+            let (errors, start) = match bytes.get(position) {
+                Some(&byte) => {
+                    let c = if self.is_case_sensitive {
+                        byte
+                    } else {
+                        byte.to_ascii_lowercase()
+                    };
+
+                    let (mut diagonal_cost, mut diagonal_start) = (0, position);
+                    starts[0] = position + 1;
+                    for i in 1..=needle.len() {
+                        let substitution = diagonal_cost + usize::from(needle[i - 1] != c);
+                        let deletion = costs[i - 1] + 1;
+                        let insertion = costs[i] + 1;
+                        // Ties go to the latest start, so occurrences are as short as possible and don't swallow
+                        // the beginning of the next one.
+                        let (cost, start) = [
+                            (substitution, diagonal_start),
+                            (deletion, starts[i - 1]),
+                            (insertion, starts[i]),
+                        ]
+                        .into_iter()
+                        .min_by_key(|&(cost, start)| (cost, Reverse(start)))
+                        .unwrap();
+                        (diagonal_cost, diagonal_start) = (costs[i], starts[i]);
+                        (costs[i], starts[i]) = (cost, start);
+                    }
+                    (costs[needle.len()], starts[needle.len()])
+                }
+                // The end of the text ends the current occurrence, another one can still follow it.
+                None => (usize::MAX, position),
+            };
+
+            // An occurrence keeps being extended while the error count doesn't grow, as it can drop again later on,
+            // up to the longest length an occurrence can have.
+            let extended = |best: &Occurrence| {
+                errors > best.errors || position - best.range.start >= needle.len() + max_errors
+            };
+            let within = errors <= max_errors;
+            // A better occurrence replaces one it overlaps, while one after it is found again once it is reported.
+            if within
+                && best
+                    .as_ref()
+                    .is_none_or(|best| errors < best.errors && start < best.range.end)
+            {
+                best = Some(Occurrence {
+                    range: start..position + 1,
+                    errors,
+                });
+            } else if let Some(found) =
+                best.take_if(|best| (within && start >= best.range.end) || extended(best))
+            {
+                // Start over after the occurrence, so the next one can't overlap it.
+                position = found.range.end;
+                for (i, (cost, start)) in costs.iter_mut().zip(&mut starts).enumerate() {
+                    (*cost, *start) = (i, position);
+                }
+                occurrences.push(found);
+                continue;
+            }
+            position += 1;
+        }
+
+        let mut end = 0;
+        occurrences.retain_mut(|occurrence| {
+            let (mut start, mut stop) = (occurrence.range.start, occurrence.range.end);
+            while !text.is_char_boundary(start) {
+                start -= 1;
+            }
+            while !text.is_char_boundary(stop) {
+                stop += 1;
+            }
+            start = start.max(end);
+            if start >= stop {
+                return false;
+            }
+            end = stop;
+            occurrence.range = start..stop;
+            true
+        });
+        occurrences
+    }
+}
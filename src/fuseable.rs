@@ -0,0 +1,163 @@
+use crate::{Fuse, Fuseable, Pattern, ScoreResult, Searcher};
+use std::borrow::Cow;
+
+/// A searchable field of a `FuseableExt` item, with its weight.
+/// Unlike `FuseProperty`, it can be created in a constant, so the fields of a type can be a static slice.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuseField {
+    /// The name of the field, passed to `FuseableExt::lookup_values`.
+    pub name: Cow<'static, str>,
+    /// The weight associated with the field.
+    pub weight: f64,
+}
+
+impl FuseField {
+    /// create a fuse field with weight 1.0.
+    pub const fn new(name: &'static str) -> Self {
+        Self::with_weight(name, 1.0)
+    }
+
+    /// create a fuse field with a specified weight.
+    pub const fn with_weight(name: &'static str, weight: f64) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            weight,
+        }
+    }
+}
+
+/// The value of a field of a `FuseableExt` item, either borrowed from the item or computed on lookup.
+/// A field can have several values, such as a list of tags, in which case the best matching value is used.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue<'a> {
+    /// a single value
+    One(Cow<'a, str>),
+    /// any number of values
+    Many(Vec<Cow<'a, str>>),
+}
+
+impl FieldValue<'_> {
+    /// Returns the values of the field.
+    pub fn as_slice(&self) -> &[Cow<'_, str>] {
+        match self {
+            Self::One(value) => std::slice::from_ref(value),
+            Self::Many(values) => values,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FieldValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::One(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for FieldValue<'_> {
+    fn from(value: String) -> Self {
+        Self::One(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for FieldValue<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::One(value)
+    }
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<Vec<T>> for FieldValue<'a> {
+    fn from(values: Vec<T>) -> Self {
+        Self::Many(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A more flexible version of `Fuseable`, accepted by every search over `Fuseable` items.
+/// Fields can be a static slice rather than a `Vec` allocated for every item of every search,
+/// and lookups can return computed values, such as formatted dates or numbers, or several values.
+/// Every `Fuseable` type implements it.
+/// # Examples:
+/// Usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse, FuseableExt, FuseField, FieldValue };
+/// use std::borrow::Cow;
+/// struct Book<'a> {
+///     title: &'a str,
+///     year: u32,
+///     tags: Vec<&'a str>,
+/// }
+///
+/// const FIELDS: &[FuseField] = &[
+///     FuseField::with_weight("title", 0.5),
+///     FuseField::new("year"),
+///     FuseField::new("tags"),
+/// ];
+///
+/// impl FuseableExt for Book<'_> {
+///     fn fields(&self) -> Cow<'_, [FuseField]> {
+///         Cow::Borrowed(FIELDS)
+///     }
+///
+///     fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>> {
+///         match name {
+///             "title" => Some(self.title.into()),
+///             "year" => Some(self.year.to_string().into()),
+///             "tags" => Some(self.tags.clone().into()),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait FuseableExt {
+    /// Returns the fields to search, with their weight.
+    fn fields(&self) -> Cow<'_, [FuseField]>;
+    /// Provided a field name as argument, returns the values of the field.
+    fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>>;
+}
+
+impl<T: Fuseable + ?Sized> FuseableExt for T {
+    fn fields(&self) -> Cow<'_, [FuseField]> {
+        Cow::Owned(
+            self.properties()
+                .into_iter()
+                .map(|property| FuseField {
+                    name: Cow::Owned(property.value),
+                    weight: property.weight,
+                })
+                .collect(),
+        )
+    }
+
+    fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>> {
+        self.lookup(name).map(FieldValue::from)
+    }
+}
+
+impl Fuse {
+    /// Looks up the values of a field, panicking if the item doesn't have it.
+    pub(crate) fn field_values<'a>(
+        item: &'a (impl FuseableExt + ?Sized),
+        field: &FuseField,
+    ) -> FieldValue<'a> {
+        item.lookup_values(&field.name).unwrap_or_else(|| {
+            panic!(
+                "Lookup Failed: Lookup doesnt contain requested value => {}.",
+                &field.name
+            );
+        })
+    }
+
+    /// Searches for a pattern in the values of a field, returning the index and the result of the best matching value.
+    pub(crate) fn search_field(
+        &self,
+        searcher: &mut Searcher,
+        pattern: Option<&Pattern>,
+        item: &(impl FuseableExt + ?Sized),
+        field: &FuseField,
+    ) -> Option<(usize, ScoreResult)> {
+        Self::field_values(item, field)
+            .as_slice()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| Some((index, searcher.search(pattern, value)?)))
+            .min_by(|(_, a), (_, b)| a.score.partial_cmp(&b.score).unwrap())
+    }
+}
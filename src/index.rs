@@ -0,0 +1,221 @@
+use crate::{Fuse, Pattern, SearchResult};
+use std::collections::HashMap;
+
+/// An inverted index from the n-grams of a list of strings to the items containing them.
+/// Searching through the index skips every item that doesn't share enough n-grams with the
+/// pattern to be within the number of errors allowed by the `threshold`, and runs the full search
+/// only on the remaining candidates. The results are always identical to searching the whole list.
+/// The index is most effective with long patterns and low thresholds, as short patterns searched with a
+/// high threshold can match nearly any string, in which case every item is searched.
+/// Always use fuse.create_index(list) to create an index.
+/// # Examples:
+/// Basic usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse };
+/// let fuse = Fuse::default();
+/// let books = ["The Silmarillion", "The Lock Artist", "The Lost Symbol"];
+/// let index = fuse.create_index(books.iter());
+/// let results = fuse.search_text_in_index("Te silm", &index, &books);
+/// ```
+pub struct NgramIndex {
+    n: usize,
+    len: usize,
+    is_case_sensitive: bool,
+    postings: HashMap<u32, Vec<usize>>,
+}
+
+/// Packs every n-gram of a byte string into an integer.
+pub(crate) fn ngrams(bytes: &[u8], n: usize) -> impl Iterator<Item = u32> + '_ {
+    bytes
+        .windows(n)
+        .map(|gram| gram.iter().fold(0_u32, |acc, &b| (acc << 8) | b as u32))
+}
+
+impl NgramIndex {
+    /// The number of items in the indexed list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the indexed list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size of the indexed n-grams.
+    pub(crate) fn ngram_size(&self) -> usize {
+        self.n
+    }
+
+    /// The items containing an n-gram, in ascending order.
+    pub(crate) fn postings(&self, gram: u32) -> &[usize] {
+        self.postings.get(&gram).map_or(&[], Vec::as_slice)
+    }
+
+    /// The number of n-grams of the pattern a string must contain to possibly match it, every string can if not positive.
+    /// Every edit to the pattern destroys at most `n` of its n-grams, so a string matching it with `k` errors
+    /// contains at least `len - n + 1 - k * n` of them.
+    pub(crate) fn required_ngrams(&self, fuse: &Fuse, pattern: &Pattern) -> i64 {
+        (pattern.len + 1) as i64 - self.n as i64 - (pattern.max_errors(fuse) * self.n) as i64
+    }
+
+    /// Marks the items sharing enough n-grams with the pattern to possibly match it, see `required_ngrams`.
+    fn mark_candidates(&self, fuse: &Fuse, pattern: &Pattern, candidates: &mut [bool]) {
+        let required = self.required_ngrams(fuse, pattern);
+        if required <= 0 {
+            candidates.fill(true);
+            return;
+        }
+
+        let mut grams: HashMap<u32, u32> = HashMap::new();
+        for gram in ngrams(pattern.text.as_bytes(), self.n) {
+            *grams.entry(gram).or_default() += 1;
+        }
+
+        let mut counts = vec![0_u32; self.len];
+        for (gram, count) in grams {
+            for &item in self.postings.get(&gram).into_iter().flatten() {
+                counts[item] += count;
+            }
+        }
+        for (candidate, count) in candidates.iter_mut().zip(counts) {
+            *candidate |= count as i64 >= required;
+        }
+    }
+
+    /// Returns which items of the list can possibly be matched by the pattern.
+    fn candidates(&self, fuse: &Fuse, pattern: &Pattern) -> Vec<bool> {
+        let mut candidates = vec![false; self.len];
+
+        // Only Bitap's error bound is known, and the index has to be normalized like the search.
+        if fuse.matcher.is_some() || fuse.is_case_sensitive != self.is_case_sensitive {
+            candidates.fill(true);
+            return candidates;
+        }
+
+        self.mark_candidates(fuse, pattern, &mut candidates);
+        if fuse.tokenize {
+            for word in pattern.text.split_whitespace() {
+                if let Some(word_pattern) = fuse.create_pattern(word) {
+                    self.mark_candidates(fuse, &word_pattern, &mut candidates);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+impl Fuse {
+    /// Creates a trigram index over a list of strings, to speed up repeated searches over the same list.
+    /// The index uses the case sensitivity of this fuse instance, searching with an instance configured differently falls back to a full scan.
+    ///
+    /// - Parameters:
+    ///   - list: Iterable over string references
+    /// - Returns: A `NgramIndex` to be passed to `search_text_in_index` along with the same list.
+    pub fn create_index<It>(&self, list: It) -> NgramIndex
+    where
+        It: IntoIterator,
+        It::Item: AsRef<str>,
+    {
+        self.create_index_with_ngram_size(list, 3)
+    }
+
+    /// Creates an index over a list of strings with n-grams of the given size, see `create_index`.
+    /// Smaller n-grams filter out fewer items but can be used with shorter patterns and higher thresholds.
+    ///
+    /// - Parameters:
+    ///   - list: Iterable over string references
+    ///   - n: The size of the n-grams, between 1 and 4.
+    pub fn create_index_with_ngram_size<It>(&self, list: It, n: usize) -> NgramIndex
+    where
+        It: IntoIterator,
+        It::Item: AsRef<str>,
+    {
+        assert!(
+            (1..=4).contains(&n),
+            "Invalid N-gram Size: {} is not between 1 and 4.",
+            n
+        );
+
+        let mut postings: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut len = 0;
+        for (index, item) in list.into_iter().enumerate() {
+            let string = if self.is_case_sensitive {
+                String::from(item.as_ref())
+            } else {
+                item.as_ref().to_ascii_lowercase()
+            };
+            for gram in ngrams(string.as_bytes(), n) {
+                let items = postings.entry(gram).or_default();
+                if items.last() != Some(&index) {
+                    items.push(index);
+                }
+            }
+            len = index + 1;
+        }
+
+        NgramIndex {
+            n,
+            len,
+            is_case_sensitive: self.is_case_sensitive,
+            postings,
+        }
+    }
+
+    /// Searches for a text pattern in a list of strings, using an index created from the same list
+    /// to only search the items that can possibly match.
+    /// Returns exactly the same results as `search_text_in_iterable`.
+    ///
+    /// - Parameters:
+    ///   - text: The pattern string to search for
+    ///   - index: An index created with `create_index` over `list`
+    ///   - list: The list of strings the index was created from
+    /// - Returns: Vec<SearchResult> containing Search results corresponding to matches found, with its `index`, its `score`, and the `ranges` of the matched characters.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// let books = [
+    ///     "The Silmarillion",
+    ///     "The Lock Artist",
+    ///     "The Lost Symbol"
+    /// ];
+    ///
+    /// let index = fuse.create_index(books.iter());
+    /// let results = fuse.search_text_in_index("Te silm", &index, &books);
+    /// ```
+    pub fn search_text_in_index(
+        &self,
+        text: &str,
+        index: &NgramIndex,
+        list: &[impl AsRef<str>],
+    ) -> Vec<SearchResult> {
+        assert_eq!(
+            index.len(),
+            list.len(),
+            "Index Mismatch: The index was not created from this list."
+        );
+
+        let Some(pattern) = self.create_pattern(text) else {
+            return vec![];
+        };
+        let mut searcher = self.searcher();
+        let mut items = vec![];
+
+        for (index, candidate) in index.candidates(self, &pattern).into_iter().enumerate() {
+            if !candidate {
+                continue;
+            }
+            if let Some(result) = searcher.search(Some(&pattern), list[index].as_ref()) {
+                items.push(SearchResult {
+                    index,
+                    score: result.score,
+                    ranges: result.ranges,
+                })
+            }
+        }
+        self.sort_results(&mut items, |index| list[index].as_ref().chars().count());
+        items
+    }
+}
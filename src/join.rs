@@ -0,0 +1,120 @@
+use crate::{sort, FResult, Fuse, FuseableExt};
+use std::collections::HashSet;
+
+/// Return type for joining two lists, a left item and one of its matches in the right list.
+#[derive(Debug, PartialEq)]
+pub struct JoinResult {
+    /// corresponding index of the item in the left list
+    pub left: usize,
+    /// corresponding index of the matched item in the right list
+    pub right: usize,
+    /// Search rating of the match, 0.0 is a perfect match 1.0 is a perfect mismatch
+    pub score: f64,
+    /// Results of every matched property of the right item, with the ranges of the matched characters.
+    pub results: Vec<FResult>,
+}
+
+impl Fuse {
+    /// Joins two lists, matching every item of the left list against the `Fuseable` items of the right list.
+    /// The key of a left item is searched in the properties of every right item, exactly like `search_text_in_fuse_list`.
+    ///
+    /// - Parameters:
+    ///   - left: The list of items to match
+    ///   - right: A list of `Fuseable` objects in which to search the keys of the left items
+    ///   - key_fn: Returns the text of a left item to search for
+    /// - Returns: Vec<JoinResult> with every match of every left item, ordered by left item and then by score.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use fuse_rust::{ Fuse, Fuseable, FuseProperty };
+    /// struct Customer<'a> {
+    ///     name: &'a str,
+    /// }
+    ///
+    /// impl Fuseable for Customer<'_> {
+    ///     fn properties(&self) -> Vec<FuseProperty> {
+    ///         vec![FuseProperty::init("name")]
+    ///     }
+    ///
+    ///     fn lookup(&self, key: &str) -> Option<&str> {
+    ///         match key {
+    ///             "name" => Some(self.name),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let crm = ["Jane Doe", "John Smith"];
+    /// let billing = [Customer { name: "Smith, John" }, Customer { name: "Jane Do" }];
+    ///
+    /// let fuse = Fuse::default();
+    /// let matches = fuse.join(&crm, &billing, |name| name);
+    /// ```
+    pub fn join<L, R>(
+        &self,
+        left: &[L],
+        right: &[R],
+        key_fn: impl Fn(&L) -> &str,
+    ) -> Vec<JoinResult>
+    where
+        R: FuseableExt,
+    {
+        let mut searcher = self.searcher();
+        let mut pairs = vec![];
+        for (left_index, item) in left.iter().enumerate() {
+            let pattern = self.create_pattern(key_fn(item));
+            let mut matches = vec![];
+            for (right_index, candidate) in right.iter().enumerate() {
+                if let Some((score, results)) =
+                    self.search_fuseable(&mut searcher, pattern.as_ref(), candidate)
+                {
+                    matches.push(JoinResult {
+                        left: left_index,
+                        right: right_index,
+                        score,
+                        results,
+                    });
+                }
+            }
+            self.sort_results(&mut matches, |index| sort::fuseable_length(&right[index]));
+            pairs.append(&mut matches);
+        }
+        pairs
+    }
+
+    /// Joins two lists like `join`, but matches every item of either list at most once.
+    /// Pairs are assigned greedily from the best score, a pair is kept only if neither of its items was already assigned.
+    ///
+    /// - Parameters:
+    ///   - left: The list of items to match
+    ///   - right: A list of `Fuseable` objects in which to search the keys of the left items
+    ///   - key_fn: Returns the text of a left item to search for
+    /// - Returns: Vec<JoinResult> with at most one match for every left item, ordered by left item.
+    pub fn join_one_to_one<L, R>(
+        &self,
+        left: &[L],
+        right: &[R],
+        key_fn: impl Fn(&L) -> &str,
+    ) -> Vec<JoinResult>
+    where
+        R: FuseableExt,
+    {
+        let mut pairs = self.join(left, right, key_fn);
+        // Stable, so ties keep preferring the lowest left and right indices.
+        pairs.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+
+        let mut assigned_left = HashSet::new();
+        let mut assigned_right = HashSet::new();
+        let mut assigned: Vec<JoinResult> = pairs
+            .into_iter()
+            .filter(|pair| {
+                !assigned_left.contains(&pair.left)
+                    && !assigned_right.contains(&pair.right)
+                    && assigned_left.insert(pair.left)
+                    && assigned_right.insert(pair.right)
+            })
+            .collect();
+        assigned.sort_by_key(|pair| pair.left);
+        assigned
+    }
+}
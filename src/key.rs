@@ -0,0 +1,141 @@
+use crate::{FieldValue, Fuse, FuseField, FuseableExt, FuseableSearchResult};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// A searchable key of items of type `T`, extracting the value to search with a closure.
+/// Keys search types which can't implement `Fuseable`, such as types of other crates, see `Fuse::search_by_keys`.
+pub struct Key<'a, T: ?Sized> {
+    field: FuseField,
+    extract: Arc<dyn Fn(&T) -> &str + Send + Sync + 'a>,
+}
+
+impl<'a, T: ?Sized> Key<'a, T> {
+    /// create a key with a specified name and weight, extracting its value with `extract`.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        weight: f64,
+        extract: impl Fn(&T) -> &str + Send + Sync + 'a,
+    ) -> Self {
+        Self {
+            field: FuseField {
+                name: name.into(),
+                weight,
+            },
+            extract: Arc::new(extract),
+        }
+    }
+
+    /// The name and weight of the key.
+    pub fn field(&self) -> &FuseField {
+        &self.field
+    }
+}
+
+impl<T: ?Sized> Clone for Key<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            field: self.field.clone(),
+            extract: Arc::clone(&self.extract),
+        }
+    }
+}
+
+/// An item with the keys to search, so it can be searched like any `Fuseable` item.
+struct Extracted<'a, 'k, T: ?Sized> {
+    item: &'a T,
+    keys: &'a [Key<'k, T>],
+    fields: &'a [FuseField],
+}
+
+impl<'a, 'k, T> Extracted<'a, 'k, T> {
+    /// The fields searched in every item, one per key.
+    fn fields_of(keys: &[Key<'k, T>]) -> Vec<FuseField> {
+        keys.iter().map(|key| key.field.clone()).collect()
+    }
+
+    /// Wraps every item of a list with the keys to search.
+    fn list(list: &'a [T], keys: &'a [Key<'k, T>], fields: &'a [FuseField]) -> Vec<Self> {
+        list.iter()
+            .map(|item| Extracted { item, keys, fields })
+            .collect()
+    }
+}
+
+impl<T: ?Sized> FuseableExt for Extracted<'_, '_, T> {
+    fn fields(&self) -> Cow<'_, [FuseField]> {
+        Cow::Borrowed(self.fields)
+    }
+
+    fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>> {
+        self.keys
+            .iter()
+            .find(|key| key.field.name == name)
+            .map(|key| (key.extract)(self.item).into())
+    }
+}
+
+impl Fuse {
+    /// Searches for a text pattern in a list of items, with the values to search extracted by closures.
+    /// Results are the same as implementing `Fuseable` with the keys as properties, see `search_text_in_fuse_list`.
+    /// - Parameters:
+    ///   - text: The pattern string to search for
+    ///   - list: A list of items in which to search
+    ///   - keys: The keys to search, each with its name, weight and a closure extracting its value from an item
+    /// - Returns: A list of `FuseableSearchResult` objects, in which each result `value` is the name of the matched key
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fuse_rust::{ Fuse, Key };
+    ///
+    /// struct Book {
+    ///    title: String,
+    ///    author: String,
+    /// }
+    ///
+    /// let books = [
+    ///     Book{author: "John X".into(), title: "Old Man's War fiction".into()},
+    ///     Book{author: "P.D. Mans".into(), title: "Right Ho Jeeves".into()},
+    /// ];
+    ///
+    /// let keys = [
+    ///     Key::new("title", 0.3, |book: &Book| &book.title),
+    ///     Key::new("author", 0.7, |book: &Book| &book.author),
+    /// ];
+    /// let fuse = Fuse::default();
+    /// let results = fuse.search_by_keys("man", &books, &keys);
+    /// ```
+    pub fn search_by_keys<T>(
+        &self,
+        text: &str,
+        list: &[T],
+        keys: &[Key<T>],
+    ) -> Vec<FuseableSearchResult> {
+        let fields = Extracted::fields_of(keys);
+        let list = Extracted::list(list, keys, &fields);
+        self.search_text_in_fuse_list(text, &list)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Fuse {
+    /// Asynchronously searches for a text pattern in a list of items, with the values to search extracted by closures.
+    /// See `search_by_keys` and `search_text_in_fuse_list_with_chunk_size_rayon`.
+    /// - Parameters:
+    ///   - text: The pattern string to search for
+    ///   - list: A list of items in which to search
+    ///   - keys: The keys to search, each with its name, weight and a closure extracting its value from an item
+    ///   - chunkSize: The size of a single chunk of the array, or `None` to pick one from the length of the list.
+    ///   - completion: The handler which is executed upon completion
+    pub fn search_by_keys_with_chunk_size_rayon<T: Sync>(
+        &self,
+        text: &str,
+        list: &[T],
+        keys: &[Key<T>],
+        chunk_size: impl Into<Option<usize>>,
+        completion: &dyn Fn(Vec<FuseableSearchResult>),
+    ) {
+        let fields = Extracted::fields_of(keys);
+        let list = Extracted::list(list, keys, &fields);
+        self.search_text_in_fuse_list_with_chunk_size_rayon(text, &list, chunk_size, completion)
+    }
+}
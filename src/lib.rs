@@ -5,19 +5,52 @@
 //! A super lightweight fuzzy-search library.
 //! A port of [Fuse-Swift](https://github.com/krisk/fuse-swift) written purely in rust!
 
+mod align;
+mod builder;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(any(feature = "async", feature = "rayon"))]
+mod chunks;
+mod cluster;
+mod explain;
+mod find;
+mod fuseable;
+mod index;
+mod join;
+mod key;
+mod many;
+mod matcher;
+mod path;
+mod records;
+mod searcher;
+#[cfg(feature = "simd")]
+mod simd;
+mod sort;
 #[cfg(test)]
 mod tests;
 mod utils;
 
-#[cfg(feature = "async")]
-use crossbeam_utils::thread;
-
-#[cfg(any(feature = "async", feature = "rayon"))]
-use std::sync::{Arc, Mutex};
+pub use align::{Alignment, EditOp};
+pub use builder::{ConfigError, FuseBuilder};
+pub use cluster::{Cluster, ClusterPair};
+pub use explain::{BitapMatch, Explanation, FuseableExplanation, KeyExplanation, TermExplanation};
+pub use find::Occurrence;
+pub use fuseable::{FieldValue, FuseField, FuseableExt};
+pub use index::NgramIndex;
+pub use join::JoinResult;
+pub use key::Key;
+pub use matcher::{
+    DamerauLevenshtein, JaroWinkler, Levenshtein, Matcher, SmithWaterman, Subsequence,
+};
+pub use records::Record;
+pub use searcher::Searcher;
+pub use sort::TieBreaker;
 
-/// Required for scoped threads
-use std::collections::HashMap;
 use std::ops::Range;
+use std::sync::Arc;
+
+/// Number of items of an iterable buffered before being searched.
+const BATCH_SIZE: usize = 64;
 
 /// Defines the fuseproperty object to be returned as part of the list
 /// returned by properties() implemented by the Fuseable trait.
@@ -71,7 +104,9 @@ impl FuseProperty {
 }
 
 /// A datatype to store the pattern's text, its length, a mask
-/// and a hashmap against each alphabet in the text.
+/// and a lookup table against each byte of the text.
+/// A pattern is a compiled query, it can be cloned, shared across threads
+/// and reused for any number of searches with the fuse instance that created it.
 /// Always use fuse.create_pattern("search string") to create a pattern
 /// # Examples:
 /// Basic usage:
@@ -79,12 +114,19 @@ impl FuseProperty {
 /// use fuse_rust::{ Fuse };
 /// let fuse = Fuse::default();
 /// let pattern = fuse.create_pattern("Hello");
+/// fuse.search(pattern.as_ref(), "Hello world");
+/// fuse.search(pattern.as_ref(), "Jello world");
 /// ```
+#[derive(Clone, Debug)]
 pub struct Pattern {
-    text: String,
-    len: usize,
-    mask: u64,
-    alphabet: HashMap<u8, u64>,
+    pub(crate) text: String,
+    pub(crate) len: usize,
+    pub(crate) mask: u64,
+    /// Bitap masks of every byte value, the text is matched byte by byte so multibyte characters take several entries.
+    pub(crate) alphabet: Box<[u64; 256]>,
+    /// Characters of the text, for the matchers working on unicode characters rather than bytes.
+    pub(crate) chars: Box<[char]>,
+    pub(crate) words: Option<Vec<Pattern>>,
 }
 
 /// Return type for performing a search on a list of strings
@@ -112,6 +154,8 @@ pub struct ScoreResult {
 pub struct FResult {
     /// The corresponding field name for this search result
     pub value: String,
+    /// index of the matched value of a field with several values, `0` for a field with a single value
+    pub value_index: usize,
     /// Search rating of the search result, 0.0 is a perfect match 1.0 is a perfect mismatch
     pub score: f64,
     /// Ranges of matches in the search query, useful if you want to hightlight matches.
@@ -136,7 +180,7 @@ pub struct FuseableSearchResult {
 /// # Examples:
 /// Basic Usage:
 /// ```no_run
-/// # use fuse_rust::{ Fuse };
+/// # use fuse_rust::{ Fuse, TieBreaker };
 /// let fuse = Fuse{
 ///     location: 0,
 ///     distance: 100,
@@ -144,6 +188,11 @@ pub struct FuseableSearchResult {
 ///     max_pattern_length: 32,
 ///     is_case_sensitive: false,
 ///     tokenize: false,
+///     min_match_char_length: 1,
+///     find_all_matches: false,
+///     matcher: None,
+///     should_sort: true,
+///     tie_breaker: TieBreaker::Index,
 /// };
 /// ```
 pub struct Fuse {
@@ -153,12 +202,22 @@ pub struct Fuse {
     pub distance: i32,
     /// threshold for the search algorithm to give up at, 0.0 is perfect match 1.0 is imperfect match
     pub threshold: f64,
-    /// maximum allowed pattern length
+    /// unused, patterns of any length are searched
     pub max_pattern_length: i32,
     /// check for lowercase and uppercase seperately
     pub is_case_sensitive: bool,
     /// tokenize search patterns
     pub tokenize: bool,
+    /// minimum length of a matched range, shorter ranges are discarded from the results
+    pub min_match_char_length: usize,
+    /// keep scanning the string for matches even after a perfect match has been found, and highlight every occurrence
+    pub find_all_matches: bool,
+    /// algorithm used to score strings, `None` uses the default Bitap algorithm
+    pub matcher: Option<Arc<dyn Matcher>>,
+    /// sort results by score, otherwise results keep the order of the list
+    pub should_sort: bool,
+    /// how results with the same score are ordered
+    pub tie_breaker: TieBreaker,
 }
 
 impl std::default::Default for Fuse {
@@ -170,16 +229,58 @@ impl std::default::Default for Fuse {
             max_pattern_length: 32,
             is_case_sensitive: false,
             tokenize: false,
+            min_match_char_length: 1,
+            find_all_matches: false,
+            matcher: None,
+            should_sort: true,
+            tie_breaker: TieBreaker::Index,
         }
     }
 }
 
+impl Pattern {
+    /// The text of the pattern, lowercased unless the fuse instance that created it is case sensitive.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Largest number of errors a Bitap match can be found with, following the error levels the search goes through.
+    pub(crate) fn max_errors(&self, fuse: &Fuse) -> usize {
+        (1..self.len)
+            .take_while(|&e| {
+                utils::calculate_score(
+                    self.len,
+                    e as i32,
+                    fuse.location,
+                    fuse.location,
+                    fuse.distance,
+                ) <= fuse.threshold
+            })
+            .last()
+            .unwrap_or(0)
+    }
+}
+
 impl Fuse {
     /// Creates a pattern object from input string.
     ///
     /// - Parameter string: A string from which to create the pattern object
     /// - Returns: A tuple containing pattern metadata
     pub fn create_pattern(&self, string: &str) -> Option<Pattern> {
+        let mut pattern = self.create_word_pattern(string)?;
+        if self.tokenize {
+            pattern.words = Some(
+                pattern
+                    .text
+                    .split_whitespace()
+                    .filter_map(|x| self.create_word_pattern(x))
+                    .collect(),
+            );
+        }
+        Some(pattern)
+    }
+
+    fn create_word_pattern(&self, string: &str) -> Option<Pattern> {
         let lowercase = string.to_lowercase();
         let pattern = if self.is_case_sensitive {
             string
@@ -196,156 +297,15 @@ impl Fuse {
             let new_pattern = Pattern {
                 text: String::from(pattern),
                 len,
-                mask: 1 << (len - 1),
+                mask: 1_u64.checked_shl(len as u32 - 1).unwrap_or(0),
                 alphabet,
+                chars: matcher::fold_chars(self, string).0.into(),
+                words: None,
             };
             Some(new_pattern)
         }
     }
 
-    #[allow(clippy::single_range_in_vec_init)]
-    fn search_util(&self, pattern: &Pattern, string: &str) -> ScoreResult {
-        let string = if self.is_case_sensitive {
-            String::from(string)
-        } else {
-            string.to_ascii_lowercase()
-        };
-
-        let string_chars = string.as_bytes();
-        let text_length = string.len();
-
-        // Exact match
-        if pattern.text == string {
-            return ScoreResult {
-                score: 0.,
-                ranges: vec![0..text_length],
-            };
-        }
-
-        let location = self.location;
-        let distance = self.distance;
-        let mut threshold = self.threshold;
-
-        let mut best_location = string.find(&pattern.text).unwrap_or(0_usize);
-
-        let mut match_mask_arr = vec![0; text_length];
-
-        let mut index = string[best_location..].find(&pattern.text);
-
-        let mut score;
-
-        while index.is_some() {
-            let i = best_location + index.unwrap();
-            score = utils::calculate_score(pattern.len, 0, i as i32, location, distance);
-
-            threshold = threshold.min(score);
-
-            best_location = i + pattern.len;
-
-            index = string[best_location..].find(&pattern.text);
-
-            for idx in 0..pattern.len {
-                match_mask_arr[i + idx] = 1;
-            }
-        }
-
-        score = 1.;
-        let mut bin_max = pattern.len + text_length;
-        let mut last_bit_arr = vec![];
-
-        let text_count = string_chars.len();
-
-        for i in 0..pattern.len {
-            let mut bin_min = 0;
-            let mut bin_mid = bin_max;
-            while bin_min < bin_mid {
-                if utils::calculate_score(
-                    pattern.len,
-                    i as i32,
-                    location,
-                    location + bin_mid as i32,
-                    distance,
-                ) <= threshold
-                {
-                    bin_min = bin_mid;
-                } else {
-                    bin_max = bin_mid;
-                }
-                bin_mid = ((bin_max - bin_min) / 2) + bin_min;
-            }
-            bin_max = bin_mid;
-
-            let start = 1.max(location - bin_mid as i32 + 1) as usize;
-            let finish = text_length.min(location as usize + bin_mid) + pattern.len;
-
-            let mut bit_arr = vec![0; finish + 2];
-
-            bit_arr[finish + 1] = (1 << i) - 1;
-
-            if start > finish {
-                continue;
-            };
-
-            let mut current_location_index: usize = 0;
-            for j in (start as u64..=finish as u64).rev() {
-                let current_location: usize = (j - 1) as usize;
-                let char_match: u64 = *(if current_location < text_count {
-                    current_location_index = current_location_index
-                        .checked_sub(1)
-                        .unwrap_or(current_location);
-                    pattern
-                        .alphabet
-                        .get(string.as_bytes().get(current_location_index).unwrap())
-                } else {
-                    None
-                })
-                .unwrap_or(&0);
-
-                if char_match != 0 {
-                    match_mask_arr[current_location] = 1;
-                }
-
-                let j2 = j as usize;
-                bit_arr[j2] = ((bit_arr[j2 + 1] << 1) | 1) & char_match;
-                if i > 0 {
-                    bit_arr[j2] |= (((last_bit_arr[j2 + 1] | last_bit_arr[j2]) << 1_u64) | 1)
-                        | last_bit_arr[j2 + 1];
-                };
-
-                if (bit_arr[j2] & pattern.mask) != 0 {
-                    score = utils::calculate_score(
-                        pattern.len,
-                        i as i32,
-                        location,
-                        current_location as i32,
-                        distance,
-                    );
-
-                    if score <= threshold {
-                        threshold = score;
-                        best_location = current_location;
-
-                        if best_location as i32 <= location {
-                            break;
-                        };
-                    }
-                }
-            }
-            if utils::calculate_score(pattern.len, i as i32 + 1, location, location, distance)
-                > threshold
-            {
-                break;
-            }
-
-            last_bit_arr = bit_arr.clone();
-        }
-
-        ScoreResult {
-            score,
-            ranges: utils::find_ranges(&match_mask_arr).unwrap(),
-        }
-    }
-
     /// Searches for a pattern in a given string.
     /// - Parameters:
     ///   - pattern: The pattern to search for. This is created by calling `createPattern`
@@ -359,44 +319,7 @@ impl Fuse {
     /// fuse.search(pattern.as_ref(), "some string");
     /// ```
     pub fn search(&self, pattern: Option<&Pattern>, string: &str) -> Option<ScoreResult> {
-        let pattern = pattern?;
-
-        if self.tokenize {
-            let word_patterns = pattern
-                .text
-                .split_whitespace()
-                .filter_map(|x| self.create_pattern(x));
-
-            let full_pattern_result = self.search_util(pattern, string);
-
-            let (length, results) = word_patterns.fold(
-                (0, full_pattern_result),
-                |(n, mut total_result), pattern| {
-                    let mut result = self.search_util(&pattern, string);
-                    total_result.score += result.score;
-                    total_result.ranges.append(&mut result.ranges);
-                    (n + 1, total_result)
-                },
-            );
-
-            let averaged_result = ScoreResult {
-                score: results.score / (length + 1) as f64,
-                ranges: results.ranges,
-            };
-
-            if (averaged_result.score - 1.0).abs() < 0.00001 {
-                None
-            } else {
-                Some(averaged_result)
-            }
-        } else {
-            let result = self.search_util(pattern, string);
-            if (result.score - 1.0).abs() < 0.00001 {
-                None
-            } else {
-                Some(result)
-            }
-        }
+        self.searcher().search(pattern, string)
     }
 }
 
@@ -485,21 +408,83 @@ impl Fuse {
         It::Item: AsRef<str>,
     {
         let pattern = self.create_pattern(text);
+        let mut searcher = self.searcher();
         let mut items = vec![];
 
-        for (index, item) in list.into_iter().enumerate() {
-            if let Some(result) = self.search(pattern.as_ref(), item.as_ref()) {
-                items.push(SearchResult {
-                    index,
-                    score: result.score,
-                    ranges: result.ranges,
-                })
+        // Items are searched in small batches, which the `simd` feature filters several at a time.
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        let mut offset = 0;
+        // The items are gone once searched, so their length is kept in case it's needed to break ties.
+        let mut lengths = vec![];
+        for item in list {
+            if matches!(self.tie_breaker, TieBreaker::Length) {
+                lengths.push(item.as_ref().chars().count());
+            }
+            batch.push(item);
+            if batch.len() == BATCH_SIZE {
+                searcher.search_list(pattern.as_ref(), &batch, offset, &mut items);
+                offset += batch.len();
+                batch.clear();
             }
         }
-        items.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        searcher.search_list(pattern.as_ref(), &batch, offset, &mut items);
+        self.sort_results(&mut items, |index| lengths[index]);
         items
     }
 
+    /// Searches for a pattern in a list of `Fuseable` items, pushing the matched items to `items`.
+    /// - Parameters:
+    ///   - offset: The index of the first item of `list`, added to the index of the results.
+    pub(crate) fn search_fuseable_list(
+        &self,
+        searcher: &mut Searcher,
+        pattern: Option<&Pattern>,
+        list: &[impl FuseableExt],
+        offset: usize,
+        items: &mut Vec<FuseableSearchResult>,
+    ) {
+        for (index, item) in list.iter().enumerate() {
+            if let Some((score, results)) = self.search_fuseable(searcher, pattern, item) {
+                items.push(FuseableSearchResult {
+                    index: offset + index,
+                    score,
+                    results,
+                })
+            }
+        }
+    }
+
+    /// Searches for a pattern in every property of a `Fuseable` item.
+    /// - Returns: The average weighted score of the matched properties and their results, None if no property matched.
+    pub(crate) fn search_fuseable(
+        &self,
+        searcher: &mut Searcher,
+        pattern: Option<&Pattern>,
+        item: &impl FuseableExt,
+    ) -> Option<(f64, Vec<FResult>)> {
+        let mut total_score = 0.0;
+        let mut property_results = vec![];
+        for field in item.fields().iter() {
+            if let Some((value_index, result)) = self.search_field(searcher, pattern, item, field) {
+                let (_, score) = utils::weigh_score(result.score, field.weight);
+                total_score += score;
+
+                property_results.push(FResult {
+                    value: field.name.to_string(),
+                    value_index,
+                    score,
+                    ranges: result.ranges,
+                });
+            }
+        }
+        if property_results.is_empty() {
+            return None;
+        }
+
+        let count = property_results.len() as f64;
+        Some((total_score / count, property_results))
+    }
+
     /// Searches for a text pattern in an array of `Fuseable` objects.
     /// - Parameters:
     ///   - text: The pattern string to search for
@@ -544,57 +529,13 @@ impl Fuse {
     pub fn search_text_in_fuse_list(
         &self,
         text: &str,
-        list: &[impl Fuseable],
+        list: &[impl FuseableExt],
     ) -> Vec<FuseableSearchResult> {
         let pattern = self.create_pattern(text);
         let mut result = vec![];
-        for (index, item) in list.iter().enumerate() {
-            let mut scores = vec![];
-            let mut total_score = 0.0;
-
-            let mut property_results = vec![];
-            item.properties().iter().for_each(|property| {
-                let value = item.lookup(&property.value).unwrap_or_else(|| {
-                    panic!(
-                        "Lookup Failed: Lookup doesnt contain requested value => {}.",
-                        &property.value
-                    );
-                });
-                if let Some(result) = self.search(pattern.as_ref(), value) {
-                    let weight = if (property.weight - 1.0).abs() < 0.00001 {
-                        1.0
-                    } else {
-                        1.0 - property.weight
-                    };
-                    let score = if result.score == 0.0 && (weight - 1.0).abs() < f64::EPSILON {
-                        0.001
-                    } else {
-                        result.score
-                    } * weight;
-                    total_score += score;
-
-                    scores.push(score);
-
-                    property_results.push(FResult {
-                        value: String::from(&property.value),
-                        score,
-                        ranges: result.ranges,
-                    });
-                }
-            });
-            if scores.is_empty() {
-                continue;
-            }
+        self.search_fuseable_list(&mut self.searcher(), pattern.as_ref(), list, 0, &mut result);
 
-            let count = scores.len() as f64;
-            result.push(FuseableSearchResult {
-                index,
-                score: total_score / count,
-                results: property_results,
-            })
-        }
-
-        result.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        self.sort_results(&mut result, |index| sort::fuseable_length(&list[index]));
         result
     }
 }
@@ -606,7 +547,7 @@ impl Fuse {
     /// - Parameters:
     ///   - text: The pattern string to search for
     ///   - list: &[&str] A reference to a slice of string references.
-    ///   - chunkSize: The size of a single chunk of the array. For example, if the slice has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic.
+    ///   - chunkSize: The size of a single chunk of the array. For example, if the slice has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Pass `None` to pick a size from the length of the list and the number of threads, see the README for the tradeoffs. A chunk size of `0` is treated as `1`.
     ///   - completion: The handler which is executed upon completion
     ///
     /// # Example:
@@ -627,54 +568,22 @@ impl Fuse {
         &self,
         text: &str,
         list: &[&str],
-        chunk_size: usize,
+        chunk_size: impl Into<Option<usize>>,
         completion: &dyn Fn(Vec<SearchResult>),
     ) {
-        let pattern = Arc::new(self.create_pattern(text));
-
-        let item_queue = Arc::new(Mutex::new(Some(vec![])));
-        let count = list.len();
-
-        rayon::scope(|scope| {
-            (0..=count).step_by(chunk_size).for_each(|offset| {
-                let chunk = &list[offset..count.min(offset + chunk_size)];
-                let queue_ref = Arc::clone(&item_queue);
-                let pattern_ref = Arc::clone(&pattern);
-                scope.spawn(move |_| {
-                    let mut chunk_items = vec![];
-
-                    for (index, item) in chunk.iter().enumerate() {
-                        if let Some(result) = self.search((*pattern_ref).as_ref(), item) {
-                            chunk_items.push(SearchResult {
-                                index: offset + index,
-                                score: result.score,
-                                ranges: result.ranges,
-                            });
-                        }
-                    }
-
-                    let mut inner_ref = queue_ref.lock().unwrap();
-                    if let Some(item_queue) = inner_ref.as_mut() {
-                        item_queue.append(&mut chunk_items);
-                    }
-                });
+        let pattern = self.create_pattern(text);
+        let mut items =
+            self.search_chunks_rayon(list, chunk_size.into(), |searcher, offset, chunk, items| {
+                searcher.search_list(pattern.as_ref(), chunk, offset, items)
             });
-        });
-
-        let mut items = Arc::try_unwrap(item_queue)
-            .ok()
-            .unwrap()
-            .into_inner()
-            .unwrap()
-            .unwrap();
-        items.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        self.sort_results(&mut items, |index| list[index].chars().count());
         completion(items);
     }
     /// Asynchronously searches for a text pattern in an array of `Fuseable` objects.
     /// - Parameters:
     ///   - text: The pattern string to search for
     ///   - list: A list of `Fuseable` objects, i.e. structs implementing the Fuseable trait in which to search
-    ///   - chunkSize: The size of a single chunk of the array. For example, if the array has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Defaults to `100`.
+    ///   - chunkSize: The size of a single chunk of the array. For example, if the array has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Pass `None` to pick a size from the length of the list and the number of threads, see the README for the tradeoffs. A chunk size of `0` is treated as `1`.
     ///   - completion: The handler which is executed upon completion
     ///     Each `Fuseable` object contains a `properties` method which returns `FuseProperty` array. Each `FuseProperty` is a struct containing a `value` (the name of the field which should be included in the search), and a `weight` (how much "weight" to assign to the score)
     ///
@@ -717,83 +626,17 @@ impl Fuse {
         &self,
         text: &str,
         list: &[T],
-        chunk_size: usize,
+        chunk_size: impl Into<Option<usize>>,
         completion: &dyn Fn(Vec<FuseableSearchResult>),
     ) where
-        T: Fuseable + std::marker::Sync,
+        T: FuseableExt + std::marker::Sync,
     {
-        let pattern = Arc::new(self.create_pattern(text));
-
-        let item_queue = Arc::new(Mutex::new(Some(vec![])));
-        let count = list.len();
-
-        rayon::scope(|scope| {
-            (0..=count).step_by(chunk_size).for_each(|offset| {
-                let chunk = &list[offset..count.min(offset + chunk_size)];
-                let queue_ref = Arc::clone(&item_queue);
-                let pattern_ref = Arc::clone(&pattern);
-                scope.spawn(move |_| {
-                    let mut chunk_items = vec![];
-
-                    for (index, item) in chunk.iter().enumerate() {
-                        let mut scores = vec![];
-                        let mut total_score = 0.0;
-
-                        let mut property_results = vec![];
-                        item.properties().iter().for_each(|property| {
-                            let value = item.lookup(&property.value).unwrap_or_else(|| {
-                                panic!(
-                                    "Lookup doesnt contain requested value => {}.",
-                                    &property.value
-                                )
-                            });
-                            if let Some(result) = self.search((*pattern_ref).as_ref(), value) {
-                                let weight = if (property.weight - 1.0).abs() < 0.00001 {
-                                    1.0
-                                } else {
-                                    1.0 - property.weight
-                                };
-                                // let score = if result.score == 0.0 && weight == 1.0 { 0.001 } else { result.score } * weight;
-                                let score = result.score * weight;
-                                total_score += score;
-
-                                scores.push(score);
-
-                                property_results.push(FResult {
-                                    value: String::from(&property.value),
-                                    score,
-                                    ranges: result.ranges,
-                                });
-                            }
-                        });
-
-                        if scores.is_empty() {
-                            continue;
-                        }
-
-                        let count = scores.len() as f64;
-                        chunk_items.push(FuseableSearchResult {
-                            index,
-                            score: total_score / count,
-                            results: property_results,
-                        })
-                    }
-
-                    let mut inner_ref = queue_ref.lock().unwrap();
-                    if let Some(item_queue) = inner_ref.as_mut() {
-                        item_queue.append(&mut chunk_items);
-                    }
-                });
+        let pattern = self.create_pattern(text);
+        let mut items =
+            self.search_chunks_rayon(list, chunk_size.into(), |searcher, offset, chunk, items| {
+                self.search_fuseable_list(searcher, pattern.as_ref(), chunk, offset, items)
             });
-        });
-
-        let mut items = Arc::try_unwrap(item_queue)
-            .ok()
-            .unwrap()
-            .into_inner()
-            .unwrap()
-            .unwrap();
-        items.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        self.sort_results(&mut items, |index| sort::fuseable_length(&list[index]));
         completion(items);
     }
 }
@@ -801,11 +644,12 @@ impl Fuse {
 #[cfg(feature = "async")]
 impl Fuse {
     /// Asynchronously searches for a text pattern in a slice of string references.
+    /// The chunks are searched by a pool of at most one thread per core.
     ///
     /// - Parameters:
     ///   - text: The pattern string to search for
     ///   - list: &[&str] A reference to a slice of string references.
-    ///   - chunkSize: The size of a single chunk of the array. For example, if the slice has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic.
+    ///   - chunkSize: The size of a single chunk of the array. For example, if the slice has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Pass `None` to pick a size from the length of the list and the number of threads, see the README for the tradeoffs. A chunk size of `0` is treated as `1`.
     ///   - completion: The handler which is executed upon completion
     ///
     /// # Example:
@@ -826,57 +670,28 @@ impl Fuse {
         &self,
         text: &str,
         list: &[&str],
-        chunk_size: usize,
+        chunk_size: impl Into<Option<usize>>,
         completion: &dyn Fn(Vec<SearchResult>),
     ) {
-        let pattern = Arc::new(self.create_pattern(text));
-
-        let item_queue = Arc::new(Mutex::new(Some(vec![])));
-        let count = list.len();
-
-        thread::scope(|scope| {
-            (0..=count).step_by(chunk_size).for_each(|offset| {
-                let chunk = &list[offset..count.min(offset + chunk_size)];
-                let queue_ref = Arc::clone(&item_queue);
-                let pattern_ref = Arc::clone(&pattern);
-                scope.spawn(move |_| {
-                    let mut chunk_items = vec![];
-
-                    for (index, item) in chunk.iter().enumerate() {
-                        if let Some(result) = self.search((*pattern_ref).as_ref(), item) {
-                            chunk_items.push(SearchResult {
-                                index: offset + index,
-                                score: result.score,
-                                ranges: result.ranges,
-                            });
-                        }
-                    }
-
-                    let mut inner_ref = queue_ref.lock().unwrap();
-                    if let Some(item_queue) = inner_ref.as_mut() {
-                        item_queue.append(&mut chunk_items);
-                    }
-                });
-            });
-        })
-        .unwrap();
-
-        let mut items = Arc::try_unwrap(item_queue)
-            .ok()
-            .unwrap()
-            .into_inner()
-            .unwrap()
-            .unwrap();
-        items.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        let pattern = self.create_pattern(text);
+        let mut items = self.search_chunks_threads(
+            list,
+            chunk_size.into(),
+            |searcher, offset, chunk, items| {
+                searcher.search_list(pattern.as_ref(), chunk, offset, items)
+            },
+        );
+        self.sort_results(&mut items, |index| list[index].chars().count());
         completion(items);
     }
     /// Asynchronously searches for a text pattern in an array of `Fuseable` objects.
+    /// The chunks are searched by a pool of at most one thread per core.
     /// - Parameters:
     ///   - text: The pattern string to search for
     ///   - list: A list of `Fuseable` objects, i.e. structs implementing the Fuseable trait in which to search
-    ///   - chunkSize: The size of a single chunk of the array. For example, if the array has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Defaults to `100`.
+    ///   - chunkSize: The size of a single chunk of the array. For example, if the array has `1000` items, it may be useful to split the work into 10 chunks of 100. This should ideally speed up the search logic. Pass `None` to pick a size from the length of the list and the number of threads, see the README for the tradeoffs. A chunk size of `0` is treated as `1`.
     ///   - completion: The handler which is executed upon completion
-    /// Each `Fuseable` object contains a `properties` method which returns `FuseProperty` array. Each `FuseProperty` is a struct containing a `value` (the name of the field which should be included in the search), and a `weight` (how much "weight" to assign to the score)
+    ///     Each `Fuseable` object contains a `properties` method which returns `FuseProperty` array. Each `FuseProperty` is a struct containing a `value` (the name of the field which should be included in the search), and a `weight` (how much "weight" to assign to the score)
     ///
     /// # Example
     /// ```no_run
@@ -917,84 +732,20 @@ impl Fuse {
         &self,
         text: &str,
         list: &[T],
-        chunk_size: usize,
+        chunk_size: impl Into<Option<usize>>,
         completion: &dyn Fn(Vec<FuseableSearchResult>),
     ) where
-        T: Fuseable + std::marker::Sync,
+        T: FuseableExt + std::marker::Sync,
     {
-        let pattern = Arc::new(self.create_pattern(text));
-
-        let item_queue = Arc::new(Mutex::new(Some(vec![])));
-        let count = list.len();
-
-        thread::scope(|scope| {
-            (0..=count).step_by(chunk_size).for_each(|offset| {
-                let chunk = &list[offset..count.min(offset + chunk_size)];
-                let queue_ref = Arc::clone(&item_queue);
-                let pattern_ref = Arc::clone(&pattern);
-                scope.spawn(move |_| {
-                    let mut chunk_items = vec![];
-
-                    for (index, item) in chunk.iter().enumerate() {
-                        let mut scores = vec![];
-                        let mut total_score = 0.0;
-
-                        let mut property_results = vec![];
-                        item.properties().iter().for_each(|property| {
-                            let value = item.lookup(&property.value).unwrap_or_else(|| {
-                                panic!(
-                                    "Lookup doesnt contain requested value => {}.",
-                                    &property.value
-                                )
-                            });
-                            if let Some(result) = self.search((*pattern_ref).as_ref(), &value) {
-                                let weight = if (property.weight - 1.0).abs() < 0.00001 {
-                                    1.0
-                                } else {
-                                    1.0 - property.weight
-                                };
-                                // let score = if result.score == 0.0 && weight == 1.0 { 0.001 } else { result.score } * weight;
-                                let score = result.score * weight;
-                                total_score += score;
-
-                                scores.push(score);
-
-                                property_results.push(FResult {
-                                    value: String::from(&property.value),
-                                    score,
-                                    ranges: result.ranges,
-                                });
-                            }
-                        });
-
-                        if scores.is_empty() {
-                            continue;
-                        }
-
-                        let count = scores.len() as f64;
-                        chunk_items.push(FuseableSearchResult {
-                            index,
-                            score: total_score / count,
-                            results: property_results,
-                        })
-                    }
-
-                    let mut inner_ref = queue_ref.lock().unwrap();
-                    if let Some(item_queue) = inner_ref.as_mut() {
-                        item_queue.append(&mut chunk_items);
-                    }
-                });
-            });
-        })
-        .unwrap();
-
-        let mut items = Arc::try_unwrap(item_queue)
-            .ok()
-            .unwrap()
-            .into_inner()
-            .unwrap()
-            .unwrap();
-        items.sort_unstable_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+        let pattern = self.create_pattern(text);
+        let mut items = self.search_chunks_threads(
+            list,
+            chunk_size.into(),
+            |searcher, offset, chunk, items| {
+                self.search_fuseable_list(searcher, pattern.as_ref(), chunk, offset, items)
+            },
+        );
+        self.sort_results(&mut items, |index| sort::fuseable_length(&list[index]));
         completion(items);
     }
 }
@@ -0,0 +1,74 @@
+use crate::{Fuse, Pattern, SearchResult};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Number of list items searched by every query before moving on to the next ones,
+/// so that they are still in cache when searched by the following queries.
+const BLOCK_SIZE: usize = 1024;
+
+/// Number of queries searched together by a single thread.
+#[cfg(feature = "rayon")]
+const QUERY_CHUNK_SIZE: usize = 16;
+
+impl Fuse {
+    fn search_many_util<S: AsRef<str>>(
+        &self,
+        patterns: &[Option<Pattern>],
+        list: &[S],
+    ) -> Vec<Vec<SearchResult>> {
+        let mut searcher = self.searcher();
+        let mut results: Vec<Vec<SearchResult>> = patterns.iter().map(|_| vec![]).collect();
+
+        for (block_index, block) in list.chunks(BLOCK_SIZE).enumerate() {
+            for (pattern, items) in patterns.iter().zip(&mut results) {
+                searcher.search_list(pattern.as_ref(), block, block_index * BLOCK_SIZE, items);
+            }
+        }
+        for items in &mut results {
+            self.sort_results(items, |index| list[index].as_ref().chars().count());
+        }
+        results
+    }
+
+    /// Searches for many text patterns in the same list of strings.
+    /// Every pattern is compiled once, and the list is walked block by block, searching all the patterns in a
+    /// block before moving on to the next one, instead of walking the whole list once per query.
+    /// With the `rayon` feature, the queries are split between threads.
+    ///
+    /// - Parameters:
+    ///   - queries: The pattern strings to search for
+    ///   - list: The list of strings in which to search
+    /// - Returns: A Vec<SearchResult> for every query, in the order of the queries, each identical to what `search_text_in_iterable` returns for that query.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// let books = [
+    ///     "The Silmarillion",
+    ///     "The Lock Artist",
+    ///     "The Lost Symbol"
+    /// ];
+    ///
+    /// let results = fuse.search_many(&["Te silm", "lock"], &books);
+    /// ```
+    pub fn search_many<Q, S>(&self, queries: &[Q], list: &[S]) -> Vec<Vec<SearchResult>>
+    where
+        Q: AsRef<str>,
+        S: AsRef<str> + Sync,
+    {
+        let patterns: Vec<Option<Pattern>> = queries
+            .iter()
+            .map(|query| self.create_pattern(query.as_ref()))
+            .collect();
+
+        #[cfg(feature = "rayon")]
+        return patterns
+            .par_chunks(QUERY_CHUNK_SIZE)
+            .flat_map_iter(|patterns| self.search_many_util(patterns, list))
+            .collect();
+
+        #[cfg(not(feature = "rayon"))]
+        self.search_many_util(&patterns, list)
+    }
+}
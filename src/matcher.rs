@@ -0,0 +1,584 @@
+use crate::{utils, Fuse, Pattern, ScoreResult};
+use std::ops::Range;
+
+/// Implementable trait for the scoring algorithm used by a `Fuse` instance.
+/// By default `Fuse` uses the Bitap algorithm, any other implementation can be selected by
+/// setting the `matcher` field.
+/// All implementations must return scores in the same space as Bitap,
+/// `0.0` for a perfect match and `1.0` for a complete mismatch, with `ranges` in bytes of the original string.
+/// # Examples:
+/// Basic Usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse, JaroWinkler };
+/// use std::sync::Arc;
+/// let fuse = Fuse {
+///     matcher: Some(Arc::new(JaroWinkler)),
+///     ..Default::default()
+/// };
+/// fuse.search_text_in_string("marhta", "martha");
+/// ```
+pub trait Matcher: Send + Sync {
+    /// Scores a string against a pattern created by `fuse.create_pattern`.
+    /// - Parameters:
+    ///   - fuse: The fuse instance performing the search, used for its config.
+    ///   - pattern: The pattern to search for, its text is already lowercased unless the search is case sensitive.
+    ///   - string: The string in which to search for the pattern, as given by the caller.
+    /// - Returns: A `ScoreResult` with a score between `0.0` (exact match) and `1.0` (not a match).
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult;
+
+    /// Scores a string against a pattern like `score`, for searches that don't need the matched ranges.
+    /// Defaults to `score`, implementations can skip the work and the memory finding the ranges takes.
+    fn score_only(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> f64 {
+        self.score(fuse, pattern, string).score
+    }
+}
+
+/// Levenshtein edit distance against the best matching substring of the string.
+/// The score is the number of insertions, deletions and substitutions divided by the pattern length.
+pub struct Levenshtein;
+
+/// Damerau-Levenshtein (optimal string alignment) edit distance against the best matching substring
+/// of the string, which also counts a transposition of two adjacent characters as a single edit.
+pub struct DamerauLevenshtein;
+
+/// Jaro-Winkler similarity of the pattern and the whole string, best suited to short strings like names.
+pub struct JaroWinkler;
+
+/// Smith-Waterman local alignment, rewarding the longest well aligned subsequence of the pattern in the string.
+pub struct SmithWaterman;
+
+/// fzf style subsequence matching, every character of the pattern has to appear in the string in order.
+/// Matches are rewarded for consecutive runs and for starting at word boundaries, camelCase humps
+/// and path separators, while gaps between matched characters are penalized.
+/// This makes it well suited for identifiers and file paths, eg. "fbr" matches "FooBarRepository".
+/// # Examples:
+/// Basic Usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse, Subsequence };
+/// use std::sync::Arc;
+/// let fuse = Fuse {
+///     matcher: Some(Arc::new(Subsequence)),
+///     ..Default::default()
+/// };
+/// let results = fuse.search_text_in_iterable("fbr", ["FooBarRepository", "src/foo/bar.rs"]);
+/// ```
+pub struct Subsequence;
+
+impl Matcher for Levenshtein {
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult {
+        edit_distance(fuse, pattern, string, false, true)
+    }
+
+    fn score_only(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> f64 {
+        edit_distance(fuse, pattern, string, false, false).score
+    }
+}
+
+impl Matcher for DamerauLevenshtein {
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult {
+        edit_distance(fuse, pattern, string, true, true)
+    }
+
+    fn score_only(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> f64 {
+        edit_distance(fuse, pattern, string, true, false).score
+    }
+}
+
+/// Splits a string into its characters, folded to lowercase unless the search is case sensitive,
+/// along with the byte range of the character each one comes from.
+/// A character can lowercase to several ones, eg. 'İ' to "i̇", which all get its range.
+/// Patterns are folded the same way, so that both sides of a match agree.
+pub(crate) fn fold_chars(fuse: &Fuse, string: &str) -> (Vec<char>, Vec<Range<usize>>) {
+    let (mut chars, mut offsets) = (vec![], vec![]);
+    for (i, c) in string.char_indices() {
+        let range = i..i + c.len_utf8();
+        if fuse.is_case_sensitive {
+            chars.push(c);
+            offsets.push(range);
+        } else {
+            for folded in c.to_lowercase() {
+                chars.push(folded);
+                offsets.push(range.clone());
+            }
+        }
+    }
+    (chars, offsets)
+}
+
+fn no_match() -> ScoreResult {
+    ScoreResult {
+        score: 1.,
+        ranges: vec![],
+    }
+}
+
+/// Computes row `i` of the edit distance table from the two rows before it, `before` being unused for the first rows.
+fn edit_distance_row(
+    pattern: &[char],
+    text: &[char],
+    i: usize,
+    transpositions: bool,
+    (before, previous): (&[usize], &[usize]),
+    row: &mut [usize],
+) {
+    row[0] = i;
+    for j in 1..=text.len() {
+        let cost = usize::from(pattern[i - 1] != text[j - 1]);
+        let mut best = (previous[j - 1] + cost)
+            .min(previous[j] + 1)
+            .min(row[j - 1] + 1);
+        if transpositions
+            && i > 1
+            && j > 1
+            && pattern[i - 1] == text[j - 2]
+            && pattern[i - 2] == text[j - 1]
+        {
+            best = best.min(before[j - 2] + 1);
+        }
+        row[j] = best;
+    }
+}
+
+/// Semi-global edit distance, the pattern has to be consumed entirely
+/// while the string may be entered and left at any position.
+/// The whole table is only kept to find the matched ranges, otherwise it is computed a few rows at a time.
+fn edit_distance(
+    fuse: &Fuse,
+    pattern: &Pattern,
+    string: &str,
+    transpositions: bool,
+    with_ranges: bool,
+) -> ScoreResult {
+    let pattern = &pattern.chars;
+    let (text, offsets) = fold_chars(fuse, string);
+    let (m, n) = (pattern.len(), text.len());
+    let errors_at_best_end = |last: &[usize]| {
+        (0..=n)
+            .map(|j| (j, last[j]))
+            .min_by_key(|&(_, errors)| errors)
+            .unwrap()
+    };
+
+    if !with_ranges {
+        let mut rows = [vec![0; n + 1], vec![0; n + 1], vec![0; n + 1]];
+        for i in 1..=m {
+            rows.rotate_left(1);
+            let [before, previous, row] = &mut rows;
+            edit_distance_row(pattern, &text, i, transpositions, (before, previous), row);
+        }
+        let (_, errors) = errors_at_best_end(&rows[2]);
+        return ScoreResult {
+            score: (errors as f64 / m as f64).min(1.),
+            ranges: vec![],
+        };
+    }
+
+    let mut dist = vec![vec![0_usize; n + 1]; m + 1];
+    for i in 1..=m {
+        let (done, rest) = dist.split_at_mut(i);
+        let before = if i > 1 { &done[i - 2] } else { &done[0] };
+        edit_distance_row(
+            pattern,
+            &text,
+            i,
+            transpositions,
+            (before, &done[i - 1]),
+            &mut rest[0],
+        );
+    }
+
+    let (end, errors) = errors_at_best_end(&dist[m]);
+
+    // Walk back through the matrix to find which characters of the string were matched.
+    let mut matched = vec![];
+    let (mut i, mut j) = (m, end);
+    while i > 0 {
+        if j > 0 && dist[i][j] == dist[i - 1][j - 1] + usize::from(pattern[i - 1] != text[j - 1]) {
+            if pattern[i - 1] == text[j - 1] {
+                matched.push(j - 1);
+            }
+            i -= 1;
+            j -= 1;
+        } else if transpositions
+            && i > 1
+            && j > 1
+            && pattern[i - 1] == text[j - 2]
+            && pattern[i - 2] == text[j - 1]
+            && dist[i][j] == dist[i - 2][j - 2] + 1
+        {
+            matched.push(j - 1);
+            matched.push(j - 2);
+            i -= 2;
+            j -= 2;
+        } else if dist[i][j] == dist[i - 1][j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    matched.reverse();
+
+    ScoreResult {
+        score: (errors as f64 / m as f64).min(1.),
+        ranges: utils::positions_to_ranges(&matched, &offsets),
+    }
+}
+
+impl Matcher for JaroWinkler {
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult {
+        let pattern = &pattern.chars;
+        let (text, offsets) = fold_chars(fuse, string);
+        let (m, n) = (pattern.len(), text.len());
+        if m == 0 || n == 0 {
+            return no_match();
+        }
+
+        let window = (m.max(n) / 2).saturating_sub(1);
+        let mut text_matched = vec![false; n];
+        let mut pattern_matches = vec![];
+        for (i, c) in pattern.iter().enumerate() {
+            let lo = i.saturating_sub(window);
+            let hi = (i + window + 1).min(n);
+            for j in lo..hi {
+                if !text_matched[j] && text[j] == *c {
+                    text_matched[j] = true;
+                    pattern_matches.push(*c);
+                    break;
+                }
+            }
+        }
+
+        if pattern_matches.is_empty() {
+            return no_match();
+        }
+
+        let matched: Vec<usize> = (0..n).filter(|&j| text_matched[j]).collect();
+        let transpositions = matched
+            .iter()
+            .zip(pattern_matches.iter())
+            .filter(|(&j, &c)| text[j] != c)
+            .count();
+
+        let count = matched.len() as f64;
+        let jaro =
+            (count / m as f64 + count / n as f64 + (count - transpositions as f64 / 2.) / count)
+                / 3.;
+        let prefix = pattern
+            .iter()
+            .zip(text.iter())
+            .take(4)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let similarity = jaro + prefix as f64 * 0.1 * (1. - jaro);
+
+        ScoreResult {
+            score: (1. - similarity).clamp(0., 1.),
+            ranges: utils::positions_to_ranges(&matched, &offsets),
+        }
+    }
+}
+
+const MATCH: i32 = 2;
+const MISMATCH: i32 = -1;
+const GAP: i32 = -1;
+
+/// Computes row `i` of the Smith-Waterman table from the previous row, returning its best cell and column.
+fn smith_waterman_row(
+    pattern: &[char],
+    text: &[char],
+    i: usize,
+    previous: &[i32],
+    row: &mut [i32],
+) -> (i32, usize) {
+    let mut best = (0, 0);
+    for j in 1..=text.len() {
+        let diagonal = if pattern[i - 1] == text[j - 1] {
+            MATCH
+        } else {
+            MISMATCH
+        };
+        let value = 0
+            .max(previous[j - 1] + diagonal)
+            .max(previous[j] + GAP)
+            .max(row[j - 1] + GAP);
+        row[j] = value;
+        if value > best.0 {
+            best = (value, j);
+        }
+    }
+    best
+}
+
+impl SmithWaterman {
+    /// Fills the table two rows at a time, or entirely to find the matched ranges,
+    /// returning the best alignment value along with the whole table if it was kept.
+    fn align(
+        pattern: &[char],
+        text: &[char],
+        with_ranges: bool,
+    ) -> (i32, usize, usize, Vec<Vec<i32>>) {
+        let (m, n) = (pattern.len(), text.len());
+        let mut best = (0, 0, 0);
+        if !with_ranges {
+            let mut rows = [vec![0; n + 1], vec![0; n + 1]];
+            for i in 1..=m {
+                rows.swap(0, 1);
+                let [previous, row] = &mut rows;
+                let (value, j) = smith_waterman_row(pattern, text, i, previous, row);
+                if value > best.0 {
+                    best = (value, i, j);
+                }
+            }
+            return (best.0, best.1, best.2, vec![]);
+        }
+
+        let mut cells = vec![vec![0_i32; n + 1]; m + 1];
+        for i in 1..=m {
+            let (done, rest) = cells.split_at_mut(i);
+            let (value, j) = smith_waterman_row(pattern, text, i, &done[i - 1], &mut rest[0]);
+            if value > best.0 {
+                best = (value, i, j);
+            }
+        }
+        (best.0, best.1, best.2, cells)
+    }
+}
+
+impl Matcher for SmithWaterman {
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult {
+        let pattern = &pattern.chars;
+        let (text, offsets) = fold_chars(fuse, string);
+        let m = pattern.len();
+
+        let (value, mut i, mut j, cells) = Self::align(pattern, &text, true);
+        if value == 0 {
+            return no_match();
+        }
+
+        let mut matched = vec![];
+        while i > 0 && j > 0 && cells[i][j] > 0 {
+            let is_match = pattern[i - 1] == text[j - 1];
+            let diagonal = if is_match { MATCH } else { MISMATCH };
+            if cells[i][j] == cells[i - 1][j - 1] + diagonal {
+                if is_match {
+                    matched.push(j - 1);
+                }
+                i -= 1;
+                j -= 1;
+            } else if cells[i][j] == cells[i - 1][j] + GAP {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+        matched.reverse();
+
+        ScoreResult {
+            score: 1. - value as f64 / (MATCH * m as i32) as f64,
+            ranges: utils::positions_to_ranges(&matched, &offsets),
+        }
+    }
+
+    fn score_only(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> f64 {
+        let pattern = &pattern.chars;
+        let (text, _) = fold_chars(fuse, string);
+        let (value, ..) = Self::align(pattern, &text, false);
+        if value == 0 {
+            return 1.;
+        }
+        1. - value as f64 / (MATCH * pattern.len() as i32) as f64
+    }
+}
+
+const SCORE_MATCH: i32 = 16;
+const SCORE_GAP_START: i32 = -3;
+const SCORE_GAP_EXTENSION: i32 = -1;
+const BONUS_BOUNDARY: i32 = SCORE_MATCH / 2;
+const BONUS_NON_WORD: i32 = SCORE_MATCH / 2;
+const BONUS_CAMEL_123: i32 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+const BONUS_CONSECUTIVE: i32 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+const BONUS_BOUNDARY_WHITE: i32 = BONUS_BOUNDARY + 2;
+const BONUS_BOUNDARY_DELIMITER: i32 = BONUS_BOUNDARY + 1;
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+#[derive(Clone, Copy, PartialEq)]
+enum CharClass {
+    White,
+    NonWord,
+    Delimiter,
+    Lower,
+    Upper,
+    Letter,
+    Number,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() {
+            Self::White
+        } else if matches!(c, '/' | '\\' | ',' | ':' | ';' | '|') {
+            Self::Delimiter
+        } else if c.is_lowercase() {
+            Self::Lower
+        } else if c.is_uppercase() {
+            Self::Upper
+        } else if c.is_numeric() {
+            Self::Number
+        } else if c.is_alphabetic() {
+            Self::Letter
+        } else {
+            Self::NonWord
+        }
+    }
+
+    fn is_word(self) -> bool {
+        !matches!(self, Self::White | Self::NonWord | Self::Delimiter)
+    }
+
+    /// Bonus for matching a character of this class right after a character of class `prev`.
+    fn bonus(self, prev: Self) -> i32 {
+        if self.is_word() {
+            match prev {
+                Self::White => return BONUS_BOUNDARY_WHITE,
+                Self::Delimiter => return BONUS_BOUNDARY_DELIMITER,
+                Self::NonWord => return BONUS_BOUNDARY,
+                _ => {}
+            }
+        }
+        if (prev == Self::Lower && self == Self::Upper)
+            || (prev != Self::Number && self == Self::Number)
+        {
+            return BONUS_CAMEL_123;
+        }
+        match self {
+            Self::NonWord | Self::Delimiter => BONUS_NON_WORD,
+            Self::White => BONUS_BOUNDARY_WHITE,
+            _ => 0,
+        }
+    }
+}
+
+/// Computes row `i` of the subsequence table from the previous one, along with where each cell continues from.
+/// `row[j]` is the best score of pattern[..=i] with pattern[i] matched at text[j],
+/// and `from[j]` the position pattern[i - 1] was matched at for that score.
+fn subsequence_row(
+    pattern: &[char],
+    text: &[char],
+    bonus: &[i32],
+    i: usize,
+    previous: &[Option<i32>],
+    row: &mut [Option<i32>],
+    from: &mut [usize],
+) {
+    row.fill(None);
+    // Best score to continue from after a gap, along with where it was matched.
+    let mut gap: Option<(i32, usize)> = None;
+    for j in i..text.len() {
+        if j >= 2 {
+            let extended = gap.map(|(score, k)| (score + SCORE_GAP_EXTENSION, k));
+            let started = previous[j - 2].map(|score| (score + SCORE_GAP_START, j - 2));
+            gap = match (extended, started) {
+                (Some(a), Some(b)) => Some(if b.0 >= a.0 { b } else { a }),
+                (a, b) => a.or(b),
+            };
+        }
+        if text[j] != pattern[i] {
+            continue;
+        }
+        let consecutive = previous[j - 1]
+            .map(|score| (score + SCORE_MATCH + bonus[j].max(BONUS_CONSECUTIVE), j - 1));
+        let gapped = gap.map(|(score, k)| (score + SCORE_MATCH + bonus[j], k));
+        let best = match (consecutive, gapped) {
+            (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        if let Some((score, k)) = best {
+            row[j] = Some(score);
+            from[j] = k;
+        }
+    }
+}
+
+impl Subsequence {
+    /// Fills the table a row at a time, returning the best score and where the last character of the pattern
+    /// was matched for it. The position every character continues from is only kept to find the matched ranges.
+    fn align(
+        pattern: &[char],
+        text: &[char],
+        string: &str,
+        with_ranges: bool,
+    ) -> Option<(i32, usize, Vec<Vec<usize>>)> {
+        let (m, n) = (pattern.len(), text.len());
+        if m == 0 || m > n {
+            return None;
+        }
+
+        let mut prev = CharClass::White;
+        let bonus: Vec<i32> = string
+            .chars()
+            .map(|c| {
+                let class = CharClass::of(c);
+                let bonus = class.bonus(prev);
+                prev = class;
+                bonus
+            })
+            .collect();
+
+        let mut rows = [vec![None; n], vec![None; n]];
+        for j in 0..n {
+            if text[j] == pattern[0] {
+                rows[1][j] = Some(SCORE_MATCH + bonus[j] * BONUS_FIRST_CHAR_MULTIPLIER);
+            }
+        }
+        let mut from = vec![vec![0; if with_ranges { n } else { 0 }]; m];
+        let mut scratch = vec![0; n];
+        for (i, from) in from.iter_mut().enumerate().skip(1) {
+            rows.swap(0, 1);
+            let [previous, row] = &mut rows;
+            let from = if with_ranges { from } else { &mut scratch };
+            subsequence_row(pattern, text, &bonus, i, previous, row, from);
+        }
+
+        let (value, end) = (0..n)
+            .filter_map(|j| rows[1][j].map(|score| (score, j)))
+            .max_by_key(|&(score, j)| (score, std::cmp::Reverse(j)))?;
+        Some((value, end, from))
+    }
+
+    /// Maps the best score of a match to the score space of `Matcher`.
+    fn normalize(value: i32, m: usize) -> f64 {
+        let perfect = SCORE_MATCH * m as i32
+            + BONUS_BOUNDARY_WHITE * (m as i32 - 1 + BONUS_FIRST_CHAR_MULTIPLIER);
+        (1. - value as f64 / perfect as f64).clamp(0., 1.)
+    }
+}
+
+impl Matcher for Subsequence {
+    fn score(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> ScoreResult {
+        let pattern = &pattern.chars;
+        let (text, offsets) = fold_chars(fuse, string);
+        let Some((value, end, from)) = Self::align(pattern, &text, string, true) else {
+            return no_match();
+        };
+
+        let mut matched = vec![end];
+        let mut j = end;
+        for i in (1..pattern.len()).rev() {
+            j = from[i][j];
+            matched.push(j);
+        }
+        matched.reverse();
+
+        ScoreResult {
+            score: Self::normalize(value, pattern.len()),
+            ranges: utils::positions_to_ranges(&matched, &offsets),
+        }
+    }
+
+    fn score_only(&self, fuse: &Fuse, pattern: &Pattern, string: &str) -> f64 {
+        let (text, _) = fold_chars(fuse, string);
+        Self::align(&pattern.chars, &text, string, false).map_or(1., |(value, ..)| {
+            Self::normalize(value, pattern.chars.len())
+        })
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Fuse, Pattern, ScoreResult, SearchResult, Searcher, TieBreaker};
+use std::borrow::Cow;
+use std::ops::Range;
+use std::path::{is_separator, Path};
+
+/// Penalty applied to matches in a directory, so that matches in the file name rank higher.
+const DIRECTORY_PENALTY: f64 = 0.2;
+
+/// Splits a string on path separators, returning the byte range of every non empty segment.
+fn segments(string: &str) -> Vec<Range<usize>> {
+    let mut segments = vec![];
+    let mut start = 0;
+    for (i, c) in string.char_indices() {
+        if is_separator(c) {
+            if i > start {
+                segments.push(start..i);
+            }
+            start = i + c.len_utf8();
+        }
+    }
+    if string.len() > start {
+        segments.push(start..string.len());
+    }
+    segments
+}
+
+/// A path converted to a string to be searched, with invalid UTF-8 replaced like `Path::to_string_lossy` does.
+struct PathString<'a> {
+    string: Cow<'a, str>,
+    /// offset in `OsStr::as_encoded_bytes` of every byte offset of `string`, `None` if the path is valid UTF-8
+    offsets: Option<Vec<usize>>,
+}
+
+impl<'a> PathString<'a> {
+    fn new(path: &'a Path) -> Self {
+        if let Some(string) = path.to_str() {
+            return Self {
+                string: Cow::Borrowed(string),
+                offsets: None,
+            };
+        }
+        let (mut string, mut offsets) = (String::new(), vec![]);
+        let mut offset = 0;
+        for chunk in path.as_os_str().as_encoded_bytes().utf8_chunks() {
+            string.push_str(chunk.valid());
+            offsets.extend(offset..offset + chunk.valid().len());
+            offset += chunk.valid().len();
+            if !chunk.invalid().is_empty() {
+                string.push(char::REPLACEMENT_CHARACTER);
+                offsets.extend(std::iter::repeat_n(
+                    offset,
+                    char::REPLACEMENT_CHARACTER.len_utf8(),
+                ));
+                offset += chunk.invalid().len();
+            }
+        }
+        offsets.push(offset);
+        Self {
+            string: Cow::Owned(string),
+            offsets: Some(offsets),
+        }
+    }
+
+    /// Converts the ranges of a match in the string to ranges in the encoded bytes of the path.
+    fn path_ranges(&self, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+        match &self.offsets {
+            None => ranges,
+            Some(offsets) => ranges
+                .into_iter()
+                .map(|range| offsets[range.start]..offsets[range.end])
+                .collect(),
+        }
+    }
+}
+
+impl Fuse {
+    fn search_path_util(
+        &self,
+        searcher: &mut Searcher,
+        patterns: &[Pattern],
+        path: &PathString,
+    ) -> Option<ScoreResult> {
+        let path_segments = segments(&path.string);
+        let (query_count, segment_count) = (patterns.len(), path_segments.len());
+        if query_count == 0 || query_count > segment_count {
+            return None;
+        }
+
+        // Score every query segment against every path segment.
+        let mut scores: Vec<Vec<Option<ScoreResult>>> = patterns
+            .iter()
+            .map(|pattern| {
+                path_segments
+                    .iter()
+                    .enumerate()
+                    .map(|(index, segment)| {
+                        let mut result =
+                            searcher.search(Some(pattern), &path.string[segment.clone()])?;
+                        if index + 1 < segment_count {
+                            result.score += (1. - result.score) * DIRECTORY_PENALTY;
+                        }
+                        Some(result)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // best[i][j] is the lowest total score of matching query segments `..=i` in order,
+        // with query segment `i` matched against path segment `j`.
+        let mut best: Vec<Vec<Option<(f64, usize)>>> = vec![vec![None; segment_count]; query_count];
+        for i in 0..query_count {
+            for j in i..segment_count {
+                let Some(score) = scores[i][j].as_ref().map(|result| result.score) else {
+                    continue;
+                };
+                best[i][j] = if i == 0 {
+                    Some((score, 0))
+                } else {
+                    (i - 1..j)
+                        .filter_map(|k| best[i - 1][k].map(|(total, _)| (total + score, k)))
+                        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+                };
+            }
+        }
+
+        // Prefer segments closer to the file name when the totals are equal.
+        let (mut j, (total, _)) = best[query_count - 1]
+            .iter()
+            .enumerate()
+            .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+            .min_by(|a, b| a.1 .0.partial_cmp(&b.1 .0).unwrap().then(b.0.cmp(&a.0)))?;
+
+        let mut ranges = vec![];
+        for i in (0..query_count).rev() {
+            let result = scores[i][j].take().unwrap();
+            let offset = path_segments[j].start;
+            ranges.splice(
+                0..0,
+                result
+                    .ranges
+                    .into_iter()
+                    .map(|range| range.start + offset..range.end + offset),
+            );
+            j = best[i][j].unwrap().1;
+        }
+
+        let score = total / query_count as f64;
+        if (score - 1.0).abs() < 0.00001 {
+            None
+        } else {
+            Some(ScoreResult {
+                score,
+                ranges: path.path_ranges(ranges),
+            })
+        }
+    }
+
+    fn create_path_patterns(&self, text: &str) -> Vec<Pattern> {
+        segments(text)
+            .into_iter()
+            .filter_map(|segment| self.create_pattern(&text[segment]))
+            .collect()
+    }
+
+    /// Searches for a text pattern in a file path.
+    /// The path and the text are split on path separators, and every segment of the text is matched,
+    /// in order, against a segment of the path. Matches in the file name are preferred over matches
+    /// in directories, so "lib" ranks "src/lib.rs" above "lib/mod.rs".
+    /// - Parameters:
+    ///   - text: the text string to search for, eg. "src/lib".
+    ///   - path: The path in which to search for the pattern.
+    /// - Returns: Some(ScoreResult) if a match is found, containing a `score` between `0.0` (exact match) and `1` (not a match), and byte `ranges` of the matched characters in `OsStr::as_encoded_bytes` of the path, the bytes of its string if it's valid UTF-8. Otherwise if a match is not found, returns None.
+    /// # Examples:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// fuse.search_text_in_path("src/lib", "crates/fuse/src/lib.rs");
+    /// ```
+    pub fn search_text_in_path(&self, text: &str, path: impl AsRef<Path>) -> Option<ScoreResult> {
+        let patterns = self.create_path_patterns(text);
+        self.search_path_util(
+            &mut self.searcher(),
+            &patterns,
+            &PathString::new(path.as_ref()),
+        )
+    }
+
+    /// Searches for a text pattern in an iterable of paths, see `search_text_in_path` for how paths are matched.
+    ///
+    /// - Parameters:
+    ///   - text: The pattern string to search for
+    ///   - paths: Iterable over anything that can be referenced as a `Path`, such as `PathBuf`, `OsStr` or `str`.
+    /// - Returns: Vec<SearchResult> containing Search results corresponding to matches found, with its `index`, its `score`, and the byte `ranges` of the matched characters, see `search_text_in_path`.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use fuse_rust::{ Fuse };
+    /// let fuse = Fuse::default();
+    /// let files = [
+    ///     "src/lib.rs",
+    ///     "src/utils.rs",
+    ///     "examples/simple-search.rs",
+    /// ];
+    ///
+    /// let results = fuse.search_paths("src/lib", files.iter());
+    /// ```
+    pub fn search_paths<It>(&self, text: &str, paths: It) -> Vec<SearchResult>
+    where
+        It: IntoIterator,
+        It::Item: AsRef<Path>,
+    {
+        let patterns = self.create_path_patterns(text);
+        let mut searcher = self.searcher();
+        let mut items = vec![];
+
+        let mut lengths = vec![];
+        for (index, path) in paths.into_iter().enumerate() {
+            let path = PathString::new(path.as_ref());
+            if matches!(self.tie_breaker, TieBreaker::Length) {
+                lengths.push(path.string.chars().count());
+            }
+            if let Some(result) = self.search_path_util(&mut searcher, &patterns, &path) {
+                items.push(SearchResult {
+                    index,
+                    score: result.score,
+                    ranges: result.ranges,
+                })
+            }
+        }
+        self.sort_results(&mut items, |index| lengths[index]);
+        items
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{FieldValue, Fuse, FuseField, FuseableExt, FuseableSearchResult};
+use std::borrow::{Borrow, Cow};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+/// A record whose values are looked up by key path, such as a `HashMap` row or, with the `json` feature,
+/// a `serde_json::Value`, searched with `Fuse::search_text_in_records` without implementing `Fuseable`.
+pub trait Record {
+    /// Provided a key path as argument, returns the values found at that path, None if there are none.
+    fn lookup_path(&self, path: &str) -> Option<FieldValue<'_>>;
+}
+
+impl<K, V, S> Record for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    /// The path is the key of the value.
+    fn lookup_path(&self, path: &str) -> Option<FieldValue<'_>> {
+        self.get(path).map(|value| value.as_ref().into())
+    }
+}
+
+#[cfg(feature = "json")]
+impl Record for serde_json::Value {
+    /// The path is a list of object keys and array indices separated by dots, such as `authors.0.name`.
+    /// Arrays met without an index have every element looked up, so `authors.name` returns the name of every author.
+    /// Strings, numbers and booleans are searchable values, nulls and objects are skipped.
+    fn lookup_path(&self, path: &str) -> Option<FieldValue<'_>> {
+        fn collect<'a>(
+            value: &'a serde_json::Value,
+            path: &[&str],
+            values: &mut Vec<Cow<'a, str>>,
+        ) {
+            use serde_json::Value;
+            match (value, path.split_first()) {
+                (Value::Array(items), Some((segment, rest))) => match segment.parse::<usize>() {
+                    Ok(index) => {
+                        if let Some(item) = items.get(index) {
+                            collect(item, rest, values);
+                        }
+                    }
+                    Err(_) => items.iter().for_each(|item| collect(item, path, values)),
+                },
+                (Value::Array(items), None) => {
+                    items.iter().for_each(|item| collect(item, path, values))
+                }
+                (Value::Object(map), Some((segment, rest))) => {
+                    if let Some(item) = map.get(*segment) {
+                        collect(item, rest, values);
+                    }
+                }
+                (Value::String(string), None) => values.push(Cow::Borrowed(string)),
+                (Value::Number(number), None) => values.push(Cow::Owned(number.to_string())),
+                (Value::Bool(boolean), None) => values.push(Cow::Owned(boolean.to_string())),
+                _ => {}
+            }
+        }
+
+        let path: Vec<&str> = path.split('.').collect();
+        let mut values = vec![];
+        collect(self, &path, &mut values);
+        match values.len() {
+            0 => None,
+            1 => values.pop().map(FieldValue::One),
+            _ => Some(FieldValue::Many(values)),
+        }
+    }
+}
+
+/// A record with the keys to search, so it can be searched like any `Fuseable` item.
+struct Keyed<'a, R: ?Sized> {
+    record: &'a R,
+    keys: &'a [FuseField],
+}
+
+impl<R: Record + ?Sized> FuseableExt for Keyed<'_, R> {
+    fn fields(&self) -> Cow<'_, [FuseField]> {
+        Cow::Borrowed(self.keys)
+    }
+
+    fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>> {
+        // Records rarely all have the same keys, so a missing key is not a match rather than an error.
+        Some(
+            self.record
+                .lookup_path(name)
+                .unwrap_or(FieldValue::Many(vec![])),
+        )
+    }
+}
+
+impl Fuse {
+    /// Searches for a text pattern in a list of records, such as `HashMap` rows or, with the `json` feature,
+    /// `serde_json::Value` objects. The values at every key path are searched and scored like the properties of a
+    /// `Fuseable` object, see `search_text_in_fuse_list`. Records missing a key path don't match on it.
+    /// - Parameters:
+    ///   - text: The pattern string to search for
+    ///   - list: A list of records in which to search
+    ///   - keys: The key paths to search, with their weight
+    /// - Returns: A list of `FuseableSearchResult` objects, in which each result `value` is the matched key path
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fuse_rust::{ Fuse, FuseField };
+    /// use std::collections::HashMap;
+    ///
+    /// let books = [
+    ///     HashMap::from([("title", "Old Man's War fiction"), ("author", "John X")]),
+    ///     HashMap::from([("title", "Right Ho Jeeves"), ("author", "P.D. Mans")]),
+    /// ];
+    ///
+    /// let keys = [FuseField::with_weight("title", 0.3), FuseField::with_weight("author", 0.7)];
+    /// let fuse = Fuse::default();
+    /// let results = fuse.search_text_in_records("man", &books, &keys);
+    /// ```
+    pub fn search_text_in_records<R: Record>(
+        &self,
+        text: &str,
+        list: &[R],
+        keys: &[FuseField],
+    ) -> Vec<FuseableSearchResult> {
+        let list: Vec<Keyed<R>> = list.iter().map(|record| Keyed { record, keys }).collect();
+        self.search_text_in_fuse_list(text, &list)
+    }
+}
@@ -0,0 +1,378 @@
+#[cfg(feature = "simd")]
+use crate::simd::{self, find_folded};
+#[cfg(not(feature = "simd"))]
+use crate::utils::find_folded;
+use crate::{utils, Alignment, Fuse, Pattern, ScoreResult, SearchResult, TermExplanation};
+use std::ops::Range;
+
+/// A reusable workspace for searching many strings with the same fuse instance.
+/// The Bitap search needs a few buffers sized after every string it scans,
+/// a searcher keeps them around between searches and compares characters case-insensitively on the fly,
+/// so scanning a list performs no heap allocations for strings that don't match,
+/// and only allocates the returned ranges of the ones that do, unless `find_all_matches` is set.
+/// All the list searches of `Fuse` use a searcher internally.
+/// # Examples:
+/// Basic usage:
+/// ```no_run
+/// use fuse_rust::{ Fuse };
+/// let fuse = Fuse::default();
+/// let pattern = fuse.create_pattern("some text");
+/// let mut searcher = fuse.searcher();
+/// searcher.search(pattern.as_ref(), "some string");
+/// searcher.search(pattern.as_ref(), "another string");
+/// ```
+pub struct Searcher<'a> {
+    fuse: &'a Fuse,
+    bit_arr: Vec<u64>,
+    last_bit_arr: Vec<u64>,
+    ranges: Vec<Range<usize>>,
+    alignment: Alignment,
+    costs: Vec<usize>,
+    #[cfg(feature = "simd")]
+    lanes: Vec<[u64; simd::LANES]>,
+}
+
+impl Fuse {
+    /// Creates a `Searcher`, to efficiently search a pattern in many strings.
+    pub fn searcher(&self) -> Searcher<'_> {
+        Searcher::new(self)
+    }
+}
+
+impl<'a> Searcher<'a> {
+    /// Creates a searcher using the config of the given fuse instance.
+    pub fn new(fuse: &'a Fuse) -> Self {
+        Self {
+            fuse,
+            bit_arr: vec![],
+            last_bit_arr: vec![],
+            ranges: vec![],
+            alignment: Alignment::empty(),
+            costs: vec![],
+            #[cfg(feature = "simd")]
+            lanes: vec![],
+        }
+    }
+
+    /// Searches for a pattern in a given string, see `Fuse::search`.
+    pub fn search(&mut self, pattern: Option<&Pattern>, string: &str) -> Option<ScoreResult> {
+        self.ranges.clear();
+        let score = self.search_tokens(pattern?, string, true)?;
+        Some(ScoreResult {
+            score,
+            ranges: self.ranges.clone(),
+        })
+    }
+
+    /// Scores a pattern against a given string without computing the matched ranges,
+    /// which never allocates when searching with the Bitap algorithm, unless `find_all_matches` is set.
+    /// - Returns: Some(score) if a match is found, with a score between `0.0` (exact match) and `1` (not a match), None otherwise.
+    pub fn score(&mut self, pattern: Option<&Pattern>, string: &str) -> Option<f64> {
+        self.search_tokens(pattern?, string, false)
+    }
+
+    /// Aligns a pattern with the text it matches in a given string, see `Fuse::align`.
+    pub fn align(&mut self, pattern: Option<&Pattern>, string: &str) -> Option<Alignment> {
+        let pattern = pattern?;
+        let (location, errors) = self.bitap(pattern, string).1?;
+        Some(self.fuse.align_at(pattern, string, location, errors))
+    }
+
+    /// Scores a single pattern, ignoring its tokens, and details how the score was computed, see `Fuse::explain`.
+    pub(crate) fn explain_term(&mut self, pattern: &Pattern, string: &str) -> TermExplanation {
+        self.ranges.clear();
+        let score = self.search_util(pattern, string, true);
+        let matched = match self.fuse.matcher {
+            Some(_) => None,
+            None => self.bitap(pattern, string).1,
+        };
+        TermExplanation {
+            pattern: pattern.text.clone(),
+            score,
+            matched: matched
+                .map(|(location, errors)| self.fuse.bitap_match(pattern, location, errors)),
+            ranges: std::mem::take(&mut self.ranges),
+        }
+    }
+
+    /// Searches for a pattern in every string of a list, pushing the matches to `items`
+    /// with their index in the list shifted by `offset`.
+    /// With the `simd` feature, strings are first run through a bit-parallel filter several at a time,
+    /// and only the ones that can possibly match are searched.
+    pub(crate) fn search_list<S: AsRef<str>>(
+        &mut self,
+        pattern: Option<&Pattern>,
+        list: &[S],
+        offset: usize,
+        items: &mut Vec<SearchResult>,
+    ) {
+        let Some(pattern) = pattern else {
+            return;
+        };
+
+        #[cfg(feature = "simd")]
+        if self.fuse.matcher.is_none() {
+            for (batch_index, batch) in list.chunks(simd::LANES).enumerate() {
+                let candidates = simd::may_match(self.fuse, pattern, batch, &mut self.lanes);
+                for (lane, item) in batch.iter().enumerate() {
+                    if !candidates[lane] {
+                        continue;
+                    }
+                    if let Some(result) = self.search(Some(pattern), item.as_ref()) {
+                        items.push(SearchResult {
+                            index: offset + batch_index * simd::LANES + lane,
+                            score: result.score,
+                            ranges: result.ranges,
+                        });
+                    }
+                }
+            }
+            return;
+        }
+
+        for (index, item) in list.iter().enumerate() {
+            if let Some(result) = self.search(Some(pattern), item.as_ref()) {
+                items.push(SearchResult {
+                    index: offset + index,
+                    score: result.score,
+                    ranges: result.ranges,
+                });
+            }
+        }
+    }
+
+    fn search_tokens(&mut self, pattern: &Pattern, string: &str, with_ranges: bool) -> Option<f64> {
+        let mut score = self.search_util(pattern, string, with_ranges);
+
+        let fuse = self.fuse;
+        if fuse.tokenize {
+            let mut count = 1;
+            let mut search_word = |word_pattern: &Pattern| {
+                score += self.search_util(word_pattern, string, with_ranges);
+                count += 1;
+            };
+            match &pattern.words {
+                Some(words) => words.iter().for_each(&mut search_word),
+                None => pattern
+                    .text
+                    .split_whitespace()
+                    .filter_map(|x| fuse.create_pattern(x))
+                    .for_each(|word_pattern| search_word(&word_pattern)),
+            }
+            score /= count as f64;
+        }
+
+        if (score - 1.0).abs() < 0.00001 {
+            None
+        } else {
+            Some(score)
+        }
+    }
+
+    fn search_util(&mut self, pattern: &Pattern, string: &str, with_ranges: bool) -> f64 {
+        let fuse = self.fuse;
+        let Some(matcher) = &fuse.matcher else {
+            let (score, found) = self.bitap(pattern, string);
+            if !with_ranges && fuse.min_match_char_length <= 1 {
+                return score;
+            }
+
+            let min_length = fuse.min_match_char_length;
+            let len = self.ranges.len();
+            if fuse.find_all_matches {
+                for occurrence in fuse.find_all(Some(pattern), string) {
+                    self.push_aligned_ranges(pattern, string, occurrence.range);
+                }
+            }
+            if let Some((location, errors)) = found.filter(|_| self.ranges.len() == len) {
+                let window = Fuse::align_window(pattern, string, location, errors);
+                self.push_aligned_ranges(pattern, string, window);
+            }
+            let matched = self.ranges.len() > len;
+            if !with_ranges {
+                self.ranges.truncate(len);
+            }
+
+            // Every matched range was shorter than the minimum length, treat it as a mismatch.
+            return if !matched && min_length > 1 {
+                1.
+            } else {
+                score
+            };
+        };
+
+        if !with_ranges && fuse.min_match_char_length <= 1 {
+            let score = matcher.score_only(fuse, pattern, string);
+            return if score > fuse.threshold { 1. } else { score };
+        }
+
+        let mut result = matcher.score(fuse, pattern, string);
+        result
+            .ranges
+            .retain(|range| range.len() >= fuse.min_match_char_length);
+        if result.score > fuse.threshold
+            || (result.ranges.is_empty() && fuse.min_match_char_length > 1)
+        {
+            result.score = 1.;
+        }
+        if with_ranges {
+            self.ranges.append(&mut result.ranges);
+        }
+        result.score
+    }
+
+    /// Aligns a pattern with a window of the text and pushes the matched ranges, reusing the alignment buffers.
+    fn push_aligned_ranges(&mut self, pattern: &Pattern, string: &str, window: Range<usize>) {
+        let fuse = self.fuse;
+        self.alignment.realign(
+            pattern.text.as_bytes(),
+            string,
+            window,
+            fuse.is_case_sensitive,
+            &mut self.costs,
+        );
+        self.alignment
+            .push_matched_ranges(string, fuse.min_match_char_length, &mut self.ranges);
+    }
+
+    /// Runs the Bitap algorithm, returning the score along with the location and the number of errors of the match it was computed for.
+    fn bitap(&mut self, pattern: &Pattern, string: &str) -> (f64, Option<(usize, usize)>) {
+        let Self {
+            fuse,
+            bit_arr,
+            last_bit_arr,
+            ..
+        } = self;
+
+        let is_case_sensitive = fuse.is_case_sensitive;
+        let fold = |c: u8| {
+            if is_case_sensitive {
+                c
+            } else {
+                c.to_ascii_lowercase()
+            }
+        };
+        let pattern_chars = pattern.text.as_bytes();
+        let string_chars = string.as_bytes();
+        let text_length = string_chars.len();
+
+        // Exact match
+        if text_length == pattern.len
+            && string_chars
+                .iter()
+                .zip(pattern_chars)
+                .all(|(&a, &b)| fold(a) == b)
+        {
+            return (0., Some((0, 0)));
+        }
+
+        let find =
+            |from: usize| find_folded(string_chars.get(from..)?, pattern_chars, is_case_sensitive);
+
+        let location = fuse.location;
+        let distance = fuse.distance;
+        let mut threshold = fuse.threshold;
+
+        let mut best_location = find(0).unwrap_or(0_usize);
+
+        let mut index = find(best_location);
+
+        let mut score;
+
+        while index.is_some() {
+            let i = best_location + index.unwrap();
+            score = utils::calculate_score(pattern.len, 0, i as i32, location, distance);
+
+            threshold = threshold.min(score);
+
+            best_location = i + pattern.len;
+
+            index = find(best_location);
+        }
+
+        score = 1.;
+        let mut found = None;
+        let mut bin_max = pattern.len + text_length;
+        last_bit_arr.clear();
+
+        for i in 0..pattern.len {
+            let mut bin_min = 0;
+            let mut bin_mid = bin_max;
+            while bin_min < bin_mid {
+                if utils::calculate_score(
+                    pattern.len,
+                    i as i32,
+                    location,
+                    location + bin_mid as i32,
+                    distance,
+                ) <= threshold
+                {
+                    bin_min = bin_mid;
+                } else {
+                    bin_max = bin_mid;
+                }
+                bin_mid = ((bin_max - bin_min) / 2) + bin_min;
+            }
+            bin_max = bin_mid;
+
+            let start = 1.max(location - bin_mid as i32 + 1) as usize;
+            let finish = if fuse.find_all_matches {
+                text_length
+            } else {
+                text_length.min(location as usize + bin_mid)
+            } + pattern.len;
+
+            bit_arr.clear();
+            bit_arr.resize(finish + 2, 0);
+
+            bit_arr[finish + 1] = 1_u64.checked_shl(i as u32).map_or(u64::MAX, |bit| bit - 1);
+
+            if start > finish {
+                continue;
+            };
+
+            for j in (start..=finish).rev() {
+                let current_location = j - 1;
+                let char_match = match string_chars.get(current_location) {
+                    Some(&c) => pattern.alphabet[fold(c) as usize],
+                    None => 0,
+                };
+
+                bit_arr[j] = ((bit_arr[j + 1] << 1) | 1) & char_match;
+                if i > 0 {
+                    bit_arr[j] |= (((last_bit_arr[j + 1] | last_bit_arr[j]) << 1_u64) | 1)
+                        | last_bit_arr[j + 1];
+                };
+
+                if (bit_arr[j] & pattern.mask) != 0 {
+                    score = utils::calculate_score(
+                        pattern.len,
+                        i as i32,
+                        location,
+                        current_location as i32,
+                        distance,
+                    );
+                    found = Some((current_location, i));
+
+                    if score <= threshold {
+                        threshold = score;
+                        best_location = current_location;
+
+                        if best_location as i32 <= location && !fuse.find_all_matches {
+                            break;
+                        };
+                    }
+                }
+            }
+            if utils::calculate_score(pattern.len, i as i32 + 1, location, location, distance)
+                > threshold
+            {
+                break;
+            }
+
+            std::mem::swap(bit_arr, last_bit_arr);
+        }
+
+        (score, found)
+    }
+}
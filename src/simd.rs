@@ -0,0 +1,129 @@
+use crate::{Fuse, Pattern};
+use memchr::{memchr, memchr2, memmem};
+
+/// Number of strings run through the bit-parallel filter at once.
+pub const LANES: usize = 8;
+
+/// Finds the first occurrence of a needle in a haystack, comparing the haystack in lowercase unless case sensitive.
+/// Uses the vectorized searches of `memchr`, falling back to scalar code on targets without SIMD support.
+pub fn find_folded(haystack: &[u8], needle: &[u8], is_case_sensitive: bool) -> Option<usize> {
+    if is_case_sensitive {
+        return memmem::find(haystack, needle);
+    }
+
+    let (&first, rest) = needle.split_first()?;
+    let mut from = 0;
+    while from + needle.len() <= haystack.len() {
+        let candidate = &haystack[from..haystack.len() - rest.len()];
+        let offset = if first.is_ascii_lowercase() {
+            memchr2(first, first.to_ascii_uppercase(), candidate)
+        } else {
+            memchr(first, candidate)
+        }?;
+        let start = from + offset;
+        if haystack[start + 1..start + needle.len()]
+            .iter()
+            .zip(rest)
+            .all(|(&a, &b)| a.to_ascii_lowercase() == b)
+        {
+            return Some(start);
+        }
+        from = start + 1;
+    }
+    None
+}
+
+/// Checks which strings of a batch contain a substring within the number of errors the Bitap search allows,
+/// running the Wu-Manber bit-parallel algorithm on `LANES` strings at once.
+/// Strings filtered out can't be matched by the search, so only the remaining ones need to be searched.
+/// - Parameters:
+///   - states: Scratch space, reused between calls.
+pub fn may_match<S: AsRef<str>>(
+    fuse: &Fuse,
+    pattern: &Pattern,
+    batch: &[S],
+    states: &mut Vec<[u64; LANES]>,
+) -> [bool; LANES] {
+    let mut matched = [false; LANES];
+    let patterns =
+        std::iter::once(pattern).chain(pattern.words.iter().flatten().filter(|_| fuse.tokenize));
+    for pattern in patterns {
+        let found = may_match_pattern(fuse, pattern, batch, states);
+        for (matched, found) in matched.iter_mut().zip(found) {
+            *matched |= found;
+        }
+    }
+    matched
+}
+
+fn may_match_pattern<S: AsRef<str>>(
+    fuse: &Fuse,
+    pattern: &Pattern,
+    batch: &[S],
+    states: &mut Vec<[u64; LANES]>,
+) -> [bool; LANES] {
+    // Patterns longer than the state don't fit the filter, every string is left to the search.
+    if pattern.len > u64::BITS as usize {
+        return [true; LANES];
+    }
+    let max_errors = pattern.max_errors(fuse);
+    let mut strings: [&[u8]; LANES] = [&[]; LANES];
+    for (string, item) in strings.iter_mut().zip(batch) {
+        *string = item.as_ref().as_bytes();
+    }
+    let max_len = strings.iter().map(|string| string.len()).max().unwrap_or(0);
+
+    // Bits are ordered like the Bitap alphabet, the first character of the pattern being the highest bit,
+    // so that a full match sets the lowest bit.
+    let first = pattern.mask;
+    let advance = |state: u64| (state >> 1) | first;
+
+    // Every state starts with its first `errors` characters deleted.
+    states.clear();
+    states.extend((0..=max_errors).map(|errors| {
+        let deleted = if errors == 0 {
+            0
+        } else {
+            (u64::MAX << (64 - errors)) >> (64 - pattern.len)
+        };
+        [deleted; LANES]
+    }));
+
+    let mut found = [0_u64; LANES];
+    for position in 0..max_len {
+        let mut char_match = [0_u64; LANES];
+        let mut active = [0_u64; LANES];
+        for lane in 0..LANES {
+            if let Some(&c) = strings[lane].get(position) {
+                let c = if fuse.is_case_sensitive {
+                    c
+                } else {
+                    c.to_ascii_lowercase()
+                };
+                char_match[lane] = pattern.alphabet[c as usize];
+                active[lane] = u64::MAX;
+            }
+        }
+
+        let mut previous = [0_u64; LANES];
+        let mut updated = [0_u64; LANES];
+        for (errors, state) in states.iter_mut().enumerate() {
+            for lane in 0..LANES {
+                let mut next = advance(state[lane]) & char_match[lane];
+                if errors > 0 {
+                    // Insertion, substitution and deletion of a character.
+                    next |= previous[lane] | advance(previous[lane]) | advance(updated[lane]);
+                }
+                previous[lane] = state[lane];
+                updated[lane] = next;
+                state[lane] = (next & active[lane]) | (state[lane] & !active[lane]);
+            }
+        }
+        let last = &states[max_errors];
+        for lane in 0..LANES {
+            found[lane] |= last[lane] & active[lane] & 1;
+        }
+    }
+
+    found.map(|found| found != 0)
+}
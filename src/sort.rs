@@ -0,0 +1,133 @@
+use crate::{Fuse, FuseableExt, FuseableSearchResult, JoinResult, SearchResult};
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+/// How results with the same score are ordered, see `Fuse::tie_breaker`.
+/// Results still tied afterwards are ordered by their index in the list, so the order is always deterministic.
+#[derive(Clone, Default)]
+pub enum TieBreaker {
+    /// the item first in the list comes first
+    #[default]
+    Index,
+    /// the item with the fewest characters comes first, for `Fuseable` items the characters of every searched value
+    Length,
+    /// a comparator of the indices of two items in the list
+    Custom(Arc<dyn Fn(usize, usize) -> Ordering + Send + Sync>),
+}
+
+impl fmt::Debug for TieBreaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index => write!(f, "Index"),
+            Self::Length => write!(f, "Length"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// A result which can be ordered by `Fuse::sort_results`.
+pub(crate) trait Ranked {
+    fn index(&self) -> usize;
+    fn score(&self) -> f64;
+}
+
+impl Ranked for SearchResult {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+impl Ranked for FuseableSearchResult {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+impl Ranked for JoinResult {
+    fn index(&self) -> usize {
+        self.right
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+/// A score in a sort key, ordered like `f64`. Scores are never NaN.
+#[derive(PartialEq)]
+struct Score(f64);
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+/// The number of characters of every searched value of a `Fuseable` item, for `TieBreaker::Length`.
+pub(crate) fn fuseable_length(item: &(impl FuseableExt + ?Sized)) -> usize {
+    item.fields()
+        .iter()
+        .map(|field| {
+            Fuse::field_values(item, field)
+                .as_slice()
+                .iter()
+                .map(|value| value.chars().count())
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+impl Fuse {
+    /// Orders results by score, then by `tie_breaker` and index, or only by index if `should_sort` is false.
+    /// - Parameters:
+    ///   - length: Returns the number of characters of the item at an index, called once per result for `TieBreaker::Length` only.
+    pub(crate) fn sort_results<R: Ranked>(
+        &self,
+        results: &mut [R],
+        length: impl Fn(usize) -> usize,
+    ) {
+        if !self.should_sort {
+            results.sort_unstable_by_key(|result| result.index());
+            return;
+        }
+        if let TieBreaker::Length = self.tie_breaker {
+            // computing the length of an item can be costly, eg. looking up the fields of a `Fuseable` item,
+            // so it is computed once per result instead of once per comparison
+            results.sort_by_cached_key(|result| {
+                (
+                    Score(result.score()),
+                    length(result.index()),
+                    result.index(),
+                )
+            });
+            return;
+        }
+        results.sort_unstable_by(|a, b| {
+            a.score()
+                .partial_cmp(&b.score())
+                .unwrap()
+                .then_with(|| match &self.tie_breaker {
+                    TieBreaker::Custom(compare) => compare(a.index(), b.index()),
+                    _ => Ordering::Equal,
+                })
+                .then_with(|| a.index().cmp(&b.index()))
+        });
+    }
+}
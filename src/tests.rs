@@ -49,3 +49,1059 @@ fn full_match_higher() {
     assert_eq!(result2[0].index, 2);
     assert_eq!(result2[1].score, result2[2].score);
 }
+
+#[test]
+fn sort_ties() {
+    use crate::TieBreaker;
+    use std::sync::Arc;
+
+    let s = &["Live3", "Live22", "Live", "Live2"];
+    let indices = |results: Vec<crate::SearchResult>| -> Vec<usize> {
+        results.iter().map(|result| result.index).collect()
+    };
+
+    let fuse = Fuse::default();
+    let results = fuse.search_text_in_iterable("live", s.iter());
+    assert!(results
+        .iter()
+        .all(|result| result.score == results[0].score));
+    assert_eq!(indices(results), vec![0, 1, 2, 3]);
+
+    #[cfg(feature = "rayon")]
+    fuse.search_text_in_string_list_rayon("live", s, 1, &|results| {
+        assert_eq!(indices(results), vec![0, 1, 2, 3]);
+    });
+
+    let fuse = Fuse {
+        tie_breaker: TieBreaker::Length,
+        ..Default::default()
+    };
+    assert_eq!(
+        indices(fuse.search_text_in_iterable("live", s.iter())),
+        vec![2, 0, 3, 1]
+    );
+
+    let fuse = Fuse {
+        tie_breaker: TieBreaker::Custom(Arc::new(|a, b| b.cmp(&a))),
+        ..Default::default()
+    };
+    assert_eq!(
+        indices(fuse.search_text_in_iterable("live", s.iter())),
+        vec![3, 2, 1, 0]
+    );
+
+    let fuse = Fuse {
+        should_sort: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        indices(fuse.search_text_in_iterable("live22", s.iter())),
+        vec![0, 1, 2, 3]
+    );
+}
+
+#[test]
+fn min_match_char_length() {
+    let fuse = Fuse {
+        min_match_char_length: 2,
+        ..Default::default()
+    };
+
+    let result = fuse.search_text_in_string("ab", "xaxbx");
+    assert!(result.is_none());
+
+    let result = fuse.search_text_in_string("ab", "xabx a b").unwrap();
+    assert_eq!(result.ranges, vec![1..3]);
+}
+
+#[test]
+fn find_all_matches() {
+    let text = "abc xxxxxxxx abd";
+
+    let fuse = Fuse::default();
+    let result = fuse.search_text_in_string("abc", text).unwrap();
+    assert_eq!(result.ranges, vec![0..3]);
+
+    let fuse = Fuse {
+        find_all_matches: true,
+        ..Default::default()
+    };
+    let result = fuse.search_text_in_string("abc", text).unwrap();
+    assert_eq!(result.score, 0.);
+    assert_eq!(result.ranges, vec![0..3, 13..15]);
+
+    // Every matched character of an approximate occurrence is highlighted, not only its beginning.
+    let text = format!("abcd{}abxd", " ".repeat(146));
+    let result = fuse.search_text_in_string("abcd", &text).unwrap();
+    assert_eq!(result.ranges, vec![0..4, 150..152, 153..154]);
+}
+
+#[test]
+fn alignment_ranges() {
+    use crate::{Alignment, EditOp};
+
+    let fuse = Fuse::default();
+    let result = fuse.search_text_in_string("abc", "cab xyz abc").unwrap();
+    assert_eq!(result.ranges, vec![8..11]);
+
+    let pattern = fuse.create_pattern("hello");
+    assert_eq!(
+        fuse.align(pattern.as_ref(), "say hxllo"),
+        Some(Alignment {
+            range: 4..9,
+            errors: 1,
+            ops: vec![
+                EditOp::Match,
+                EditOp::Substitute,
+                EditOp::Match,
+                EditOp::Match,
+                EditOp::Match
+            ],
+        })
+    );
+    let result = fuse.search(pattern.as_ref(), "say hxllo").unwrap();
+    assert_eq!(result.ranges, vec![4..5, 6..9]);
+
+    let alignment = fuse.align(pattern.as_ref(), "say helo").unwrap();
+    assert_eq!(alignment.errors, 1);
+    assert!(alignment.ops.contains(&EditOp::Delete));
+    assert_eq!(
+        fuse.search(pattern.as_ref(), "say helo").unwrap().ranges,
+        vec![4..8]
+    );
+
+    assert_eq!(fuse.align(pattern.as_ref(), "xyz"), None);
+}
+
+#[test]
+fn explain_scores() {
+    use crate::{FuseProperty, Fuseable};
+
+    let fuse = Fuse {
+        tokenize: true,
+        ..Default::default()
+    };
+    let explanation = fuse.explain("od mn war", "Old Man's War");
+    let result = fuse
+        .search_text_in_string("od mn war", "Old Man's War")
+        .unwrap();
+    assert_eq!(explanation.score, Some(result.score));
+    assert_eq!(explanation.terms.len(), 4);
+    for term in &explanation.terms {
+        let matched = term.matched.as_ref().unwrap();
+        assert!((matched.accuracy + matched.proximity - term.score).abs() < 1e-9);
+    }
+    assert_eq!(explanation.terms[3].ranges, vec![10..13]);
+    assert!(explanation.to_string().contains("\"war\": score"));
+
+    struct Book {
+        title: &'static str,
+        author: &'static str,
+    }
+
+    impl Fuseable for Book {
+        fn properties(&self) -> Vec<FuseProperty> {
+            vec![
+                FuseProperty::init_with_weight("title", 0.3),
+                FuseProperty::init_with_weight("author", 0.7),
+            ]
+        }
+
+        fn lookup(&self, key: &str) -> Option<&str> {
+            match key {
+                "title" => Some(self.title),
+                "author" => Some(self.author),
+                _ => None,
+            }
+        }
+    }
+
+    let fuse = Fuse::default();
+    let books = [Book {
+        title: "Old Man's War fiction",
+        author: "John X",
+    }];
+    let explanation = fuse.explain_fuseable("man", &books[0]);
+    let results = fuse.search_text_in_fuse_list("man", &books);
+    assert_eq!(explanation.score, Some(results[0].score));
+    assert_eq!(explanation.keys[0].factor, 0.7);
+    assert_eq!(explanation.keys[1].score, None);
+    assert!(explanation
+        .to_string()
+        .contains("average of 1 matched key(s) out of 2"));
+}
+
+#[test]
+fn builder_validates_config() {
+    use crate::{ConfigError, FuseBuilder};
+
+    let fuse = Fuse::builder()
+        .threshold(0.3)
+        .and_then(|builder| builder.distance(50))
+        .unwrap()
+        .tokenize(true)
+        .build();
+    assert_eq!(
+        (fuse.threshold, fuse.distance, fuse.tokenize),
+        (0.3, 50, true)
+    );
+
+    assert_eq!(
+        Fuse::builder().threshold(1.5).err(),
+        Some(ConfigError::InvalidThreshold(1.5))
+    );
+    assert!(Fuse::builder().threshold(f64::NAN).is_err());
+    assert_eq!(
+        Fuse::builder().location(-1).err(),
+        Some(ConfigError::InvalidLocation(-1))
+    );
+    assert_eq!(
+        Fuse::builder()
+            .min_match_char_length(0)
+            .err()
+            .unwrap()
+            .to_string(),
+        "Invalid Min Match Char Length: 0 is not at least 1."
+    );
+
+    for preset in [
+        FuseBuilder::strict(),
+        FuseBuilder::typo_tolerant(),
+        FuseBuilder::autocomplete(),
+    ] {
+        assert_eq!(preset.build().validate(), Ok(()));
+    }
+    let fuse = Fuse {
+        distance: -5,
+        ..Default::default()
+    };
+    assert_eq!(fuse.validate(), Err(ConfigError::InvalidDistance(-5)));
+
+    let fuse = FuseBuilder::typo_tolerant().build();
+    assert!(fuse
+        .search_text_in_string("teh lord", "The Lord of the Rings")
+        .is_some());
+}
+
+#[test]
+fn alternative_matchers() {
+    use crate::{DamerauLevenshtein, JaroWinkler, Levenshtein, Matcher, SmithWaterman};
+    use std::sync::Arc;
+
+    let with = |matcher: Arc<dyn Matcher>| Fuse {
+        matcher: Some(matcher),
+        ..Default::default()
+    };
+
+    let result = with(Arc::new(Levenshtein))
+        .search_text_in_string("wrold", "hello world")
+        .unwrap();
+    assert_eq!(result.score, 0.4);
+
+    let result = with(Arc::new(DamerauLevenshtein))
+        .search_text_in_string("wrold", "hello world")
+        .unwrap();
+    assert_eq!(result.score, 0.2);
+    assert_eq!(result.ranges, vec![6..11]);
+
+    let result = with(Arc::new(JaroWinkler))
+        .search_text_in_string("martha", "MARHTA")
+        .unwrap();
+    assert!((result.score - (1. - 0.9611111111111111)).abs() < 1e-9);
+
+    let result = with(Arc::new(SmithWaterman))
+        .search_text_in_string("fbr", "fbar")
+        .unwrap();
+    assert_eq!(result.ranges, vec![0..2, 3..4]);
+
+    let results = with(Arc::new(Levenshtein)).search_text_in_iterable("syrup", ["Syrup", "Live"]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].score, 0.);
+
+    // 'İ' lowercases to two characters, in the pattern as in the string
+    let result = with(Arc::new(Levenshtein))
+        .search_text_in_string("İstanbul", "İSTANBUL")
+        .unwrap();
+    assert_eq!(result.score, 0.);
+    assert_eq!(result.ranges, vec![0..9]);
+
+    // Scoring without ranges keeps a few rows of the tables, and has to agree with the full tables.
+    let matchers: [Arc<dyn Matcher>; 5] = [
+        Arc::new(Levenshtein),
+        Arc::new(DamerauLevenshtein),
+        Arc::new(JaroWinkler),
+        Arc::new(SmithWaterman),
+        Arc::new(crate::Subsequence),
+    ];
+    let fuse = Fuse::default();
+    for matcher in matchers {
+        for (pattern, string) in [
+            ("wrold", "hello world"),
+            ("fbr", "FooBarRepository"),
+            ("abc", "xaxbxcx acb"),
+            ("long pattern", "short"),
+            ("a", ""),
+        ] {
+            let pattern = fuse.create_pattern(pattern).unwrap();
+            assert_eq!(
+                matcher.score_only(&fuse, &pattern, string),
+                matcher.score(&fuse, &pattern, string).score
+            );
+        }
+    }
+}
+
+#[test]
+fn subsequence_matcher() {
+    use crate::Subsequence;
+    use std::sync::Arc;
+
+    let fuse = Fuse {
+        matcher: Some(Arc::new(Subsequence)),
+        ..Default::default()
+    };
+
+    let result = fuse
+        .search_text_in_string("fbr", "FooBarRepository")
+        .unwrap();
+    assert_eq!(result.ranges, vec![0..1, 3..4, 6..7]);
+
+    let list = ["fabric", "src/foo/bar.rs", "FooBarRepository", "rbf"];
+    let results = fuse.search_text_in_iterable("fbr", list);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[2].index, 0);
+
+    let results = fuse.search_text_in_iterable("bar", ["foobar", "foo_bar", "foo/bar"]);
+    assert_eq!(
+        results.iter().map(|r| r.index).collect::<Vec<_>>(),
+        vec![2, 1, 0]
+    );
+}
+
+#[test]
+fn path_search() {
+    use std::path::PathBuf;
+
+    let fuse = Fuse::default();
+    let paths = [
+        PathBuf::from("lib/mod.rs"),
+        PathBuf::from("src/lib.rs"),
+        PathBuf::from("src/utils.rs"),
+        PathBuf::from("examples/lib/simple.rs"),
+    ];
+
+    let results = fuse.search_paths("lib", paths.iter());
+    assert_eq!(results[0].index, 1);
+    assert_eq!(results[0].ranges, vec![4..7]);
+
+    let results = fuse.search_paths("src/lib", paths.iter());
+    assert_eq!(results[0].index, 1);
+    assert_eq!(results[0].ranges, vec![0..3, 4..7]);
+
+    assert!(fuse.search_text_in_path("src/lib", "lib.rs").is_none());
+
+    // ranges index the bytes of the path, not its lossy string where the invalid byte takes 3 bytes
+    #[cfg(unix)]
+    {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path;
+
+        let path = Path::new(OsStr::from_bytes(b"src/\xFFlib.rs"));
+        let result = fuse.search_text_in_path("src/lib", path).unwrap();
+        assert_eq!(result.ranges, vec![0..3, 5..8]);
+    }
+}
+
+/// Small xorshift generator, so the randomized tests are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn string(&mut self, alphabet: &[u8], max_len: u64) -> String {
+        let len = self.next() % (max_len + 1);
+        (0..len)
+            .map(|_| alphabet[(self.next() % alphabet.len() as u64) as usize] as char)
+            .collect()
+    }
+}
+
+fn sorted(mut results: Vec<crate::SearchResult>) -> Vec<crate::SearchResult> {
+    results.sort_by_key(|result| result.index);
+    results
+}
+
+#[test]
+fn index_matches_full_scan() {
+    let mut rng = Rng(0x2545f4914f6cdd1d);
+    let list: Vec<String> = (0..200).map(|_| rng.string(b"abcdeAB xy", 24)).collect();
+
+    for threshold in [0.0, 0.2, 0.4, 0.6, 0.8] {
+        for (tokenize, is_case_sensitive) in [(false, false), (true, false), (false, true)] {
+            let fuse = Fuse {
+                threshold,
+                tokenize,
+                is_case_sensitive,
+                location: (rng.next() % 4) as i32,
+                ..Default::default()
+            };
+            for n in 1..=4 {
+                let index = fuse.create_index_with_ngram_size(&list, n);
+                for _ in 0..8 {
+                    let query = rng.string(b"abcdeAB xy", 10);
+                    assert_eq!(
+                        sorted(fuse.search_text_in_index(&query, &index, &list)),
+                        sorted(fuse.search_text_in_iterable(&query, &list)),
+                        "query {:?}, threshold {}, n {}",
+                        query,
+                        threshold,
+                        n
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn search_many_matches_single_searches() {
+    let mut rng = Rng(0xda942042e4dd58b5);
+    let list: Vec<String> = (0..1500).map(|_| rng.string(b"abcdeAB xy", 16)).collect();
+    let queries: Vec<String> = (0..40).map(|_| rng.string(b"abcdeAB xy", 6)).collect();
+
+    let fuse = Fuse {
+        tokenize: true,
+        ..Default::default()
+    };
+    let results = fuse.search_many(&queries, &list);
+    assert_eq!(results.len(), queries.len());
+    for (query, results) in queries.iter().zip(results) {
+        assert_eq!(
+            sorted(results),
+            sorted(fuse.search_text_in_iterable(query, &list)),
+            "query {:?}",
+            query
+        );
+    }
+}
+
+#[test]
+fn cluster_products() {
+    let fuse = Fuse {
+        threshold: 0.2,
+        ..Default::default()
+    };
+    let products = [
+        "Apple iPhone 15 Pro",
+        "Samsung Galaxy S24",
+        "Aple iPhone 15 Pro",
+        "Apple iPhone 15 Pro",
+        "Samsung Galaxy S24 ",
+        "Google Pixel 8",
+    ];
+
+    let clusters = fuse.cluster(&products);
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(clusters[0].members, vec![0, 2, 3]);
+    assert_eq!(clusters[0].representative, 0);
+    assert_eq!(clusters[1].members, vec![1, 4]);
+    assert_eq!(fuse.dedup(&products), vec![0, 1, 5]);
+
+    // Too long for Bitap, compared with Levenshtein instead.
+    let long = "a".repeat(40) + &"b".repeat(40);
+    let typo = "a".repeat(40) + "c" + &"b".repeat(39);
+    assert_eq!(fuse.dedup(&[long.as_str(), typo.as_str()]), vec![0]);
+}
+
+#[test]
+fn cluster_matches_all_pairs() {
+    let mut rng = Rng(0x94d049bb133111eb);
+    let list: Vec<String> = (0..150).map(|_| rng.string(b"abcAB x", 12)).collect();
+
+    for threshold in [0.1, 0.3, 0.6] {
+        for tokenize in [false, true] {
+            let fuse = Fuse {
+                threshold,
+                tokenize,
+                ..Default::default()
+            };
+            let patterns: Vec<_> = list.iter().map(|item| fuse.create_pattern(item)).collect();
+            let mut expected = vec![];
+            for first in 0..list.len() {
+                for second in first + 1..list.len() {
+                    let forward = fuse.search(patterns[first].as_ref(), &list[second]);
+                    let backward = fuse.search(patterns[second].as_ref(), &list[first]);
+                    if let (Some(forward), Some(backward)) = (forward, backward) {
+                        let score = forward.score.max(backward.score);
+                        if score <= threshold {
+                            expected.push((first, second, score));
+                        }
+                    }
+                }
+            }
+
+            let mut pairs: Vec<_> = fuse
+                .cluster(&list)
+                .into_iter()
+                .flat_map(|cluster| cluster.pairs)
+                .map(|pair| (pair.first, pair.second, pair.score))
+                .collect();
+            pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(
+                pairs, expected,
+                "threshold {}, tokenize {}",
+                threshold, tokenize
+            );
+        }
+    }
+}
+
+#[test]
+fn join_records() {
+    use crate::{FuseProperty, Fuseable};
+
+    struct Customer {
+        name: &'static str,
+        city: &'static str,
+    }
+
+    impl Fuseable for Customer {
+        fn properties(&self) -> Vec<FuseProperty> {
+            vec![FuseProperty::init("name"), FuseProperty::init("city")]
+        }
+
+        fn lookup(&self, key: &str) -> Option<&str> {
+            match key {
+                "name" => Some(self.name),
+                "city" => Some(self.city),
+                _ => None,
+            }
+        }
+    }
+
+    let crm = ["Jon Smith", "Jane Smith", "Alice Jones"];
+    let billing = [
+        Customer {
+            name: "Jane Smyth",
+            city: "Leeds",
+        },
+        Customer {
+            name: "John Smith",
+            city: "York",
+        },
+    ];
+    let fuse = Fuse {
+        threshold: 0.4,
+        ..Default::default()
+    };
+
+    let pairs = fuse.join(&crm, &billing, |name| name);
+    assert!(pairs.iter().all(|pair| pair.left != 2));
+    assert_eq!(
+        pairs
+            .iter()
+            .map(|pair| (pair.left, pair.right))
+            .collect::<Vec<_>>(),
+        vec![(0, 1), (0, 0), (1, 0), (1, 1)]
+    );
+    assert_eq!(pairs[0].results[0].value, "name");
+
+    let assigned = fuse.join_one_to_one(&crm, &billing, |name| name);
+    assert_eq!(
+        assigned
+            .iter()
+            .map(|pair| (pair.left, pair.right))
+            .collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0)]
+    );
+}
+
+#[test]
+fn find_all_occurrences() {
+    use crate::Occurrence;
+
+    let fuse = Fuse {
+        threshold: 0.2,
+        ..Default::default()
+    };
+    let text = "The Quick brown fox, the quikc brown dog, quick quick";
+    let pattern = fuse.create_pattern("quick");
+    assert_eq!(
+        fuse.find_all(pattern.as_ref(), text),
+        vec![
+            Occurrence {
+                range: 4..9,
+                errors: 0
+            },
+            // "quik" is the whole occurrence, including the trailing "c" would be a second error.
+            Occurrence {
+                range: 25..29,
+                errors: 1
+            },
+            Occurrence {
+                range: 42..47,
+                errors: 0
+            },
+            Occurrence {
+                range: 48..53,
+                errors: 0
+            },
+        ]
+    );
+
+    let default = Fuse::default();
+    for (pattern, text, expected) in [
+        ("document", "a documant here", 2..10),
+        ("abcd", "abxd", 0..4),
+    ] {
+        let pattern = default.create_pattern(pattern);
+        assert_eq!(
+            default.find_all(pattern.as_ref(), text),
+            vec![Occurrence {
+                range: expected,
+                errors: 1
+            }]
+        );
+    }
+
+    // a better occurrence after the current one doesn't replace it
+    let pattern = default.create_pattern("ca");
+    assert_eq!(
+        default.find_all(pattern.as_ref(), "cbca"),
+        vec![
+            Occurrence {
+                range: 0..1,
+                errors: 1
+            },
+            Occurrence {
+                range: 2..4,
+                errors: 0
+            },
+        ]
+    );
+
+    let pattern = fuse.create_pattern("caf");
+    let occurrences = fuse.find_all(pattern.as_ref(), "un café, deux cafés");
+    assert_eq!(occurrences.len(), 2);
+    assert!(occurrences
+        .iter()
+        .all(|occurrence| "un café, deux cafés".is_char_boundary(occurrence.range.end)));
+
+    let long_text = "lorem ipsum dolor sit amet ".repeat(40_000) + "needle";
+    let pattern = fuse.create_pattern("neddle");
+    let occurrences = fuse.find_all(pattern.as_ref(), &long_text);
+    assert_eq!(occurrences.last().unwrap().range.end, long_text.len());
+}
+
+#[test]
+fn fuseable_ext_values() {
+    use crate::{FieldValue, FuseField, FuseableExt};
+    use std::borrow::Cow;
+
+    struct Movie {
+        title: &'static str,
+        year: u32,
+        tags: Vec<&'static str>,
+    }
+
+    const FIELDS: &[FuseField] = &[
+        FuseField::new("title"),
+        FuseField::new("year"),
+        FuseField::with_weight("tags", 0.5),
+    ];
+
+    impl FuseableExt for Movie {
+        fn fields(&self) -> Cow<'_, [FuseField]> {
+            Cow::Borrowed(FIELDS)
+        }
+
+        fn lookup_values(&self, name: &str) -> Option<FieldValue<'_>> {
+            match name {
+                "title" => Some(self.title.into()),
+                "year" => Some(self.year.to_string().into()),
+                "tags" => Some(self.tags.clone().into()),
+                _ => None,
+            }
+        }
+    }
+
+    let movies = [
+        Movie {
+            title: "Alien",
+            year: 1979,
+            tags: vec!["horror", "space"],
+        },
+        Movie {
+            title: "Heat",
+            year: 1995,
+            tags: vec!["crime", "thriller"],
+        },
+    ];
+    let fuse = Fuse::default();
+
+    let results = fuse.search_text_in_fuse_list("1995", &movies);
+    assert_eq!(results[0].index, 1);
+    assert!(results[0]
+        .results
+        .iter()
+        .any(|result| result.value == "year" && result.ranges.first() == Some(&(0..4))));
+
+    let results = fuse.search_text_in_fuse_list("thriler", &movies);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].index, 1);
+    let tags = &results[0].results[0];
+    assert_eq!(tags.value, "tags");
+    assert_eq!(tags.value_index, 1);
+    assert!(tags.score < 0.5);
+
+    let explanation = fuse.explain_fuseable("thriler", &movies[1]);
+    assert_eq!(explanation.keys[2].explanation.terms[0].pattern, "thriler");
+    assert_eq!(explanation.score, Some(results[0].score));
+}
+
+#[test]
+fn search_records() {
+    use crate::FuseField;
+    use std::collections::HashMap;
+
+    let rows = [
+        HashMap::from([("title", "Old Man's War"), ("author", "John Scalzi")]),
+        HashMap::from([("title", "The Lock Artist")]),
+    ];
+    let keys = [
+        FuseField::with_weight("title", 0.3),
+        FuseField::with_weight("author", 0.7),
+    ];
+    let fuse = Fuse::default();
+    let results = fuse.search_text_in_records("scalzi", &rows, &keys);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].results[0].value, "author");
+
+    let results = fuse.search_text_in_records("lock", &rows, &keys);
+    assert_eq!(results[0].index, 1);
+}
+
+#[test]
+fn search_by_keys() {
+    use crate::{FuseProperty, Fuseable, Key};
+
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Fuseable for Book {
+        fn properties(&self) -> Vec<FuseProperty> {
+            vec![
+                FuseProperty::init_with_weight("title", 0.3),
+                FuseProperty::init_with_weight("author", 0.7),
+            ]
+        }
+
+        fn lookup(&self, key: &str) -> Option<&str> {
+            match key {
+                "title" => Some(&self.title),
+                "author" => Some(&self.author),
+                _ => None,
+            }
+        }
+    }
+
+    let books = [
+        ("Old Man's War fiction", "John X"),
+        ("Right Ho Jeeves", "P.D. Mans"),
+        ("The Lock Artist", "Steve Hamilton"),
+    ]
+    .map(|(title, author)| Book {
+        title: title.into(),
+        author: author.into(),
+    });
+    let keys = [
+        Key::new("title", 0.3, |book: &Book| &book.title),
+        Key::new("author", 0.7, |book: &Book| &book.author),
+    ];
+    let fuse = Fuse::default();
+    let results = fuse.search_by_keys("man", &books, &keys);
+    assert_eq!(results, fuse.search_text_in_fuse_list("man", &books));
+
+    #[cfg(feature = "rayon")]
+    fuse.search_by_keys_with_chunk_size_rayon("man", &books, &keys, 10, &|rayon_results| {
+        assert_eq!(rayon_results, results);
+    });
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn search_json_records() {
+    use crate::{FieldValue, FuseField, Record};
+    use serde_json::json;
+
+    let books = [
+        json!({"title": "Old Man's War", "year": 2005, "authors": [{"name": "John Scalzi"}]}),
+        json!({"title": "Good Omens", "authors": [{"name": "Terry Pratchett"}, {"name": "Neil Gaiman"}]}),
+    ];
+    assert_eq!(
+        books[1].lookup_path("authors.1.name"),
+        Some(FieldValue::One("Neil Gaiman".into()))
+    );
+    assert_eq!(
+        books[1].lookup_path("authors.name"),
+        Some(FieldValue::Many(vec![
+            "Terry Pratchett".into(),
+            "Neil Gaiman".into()
+        ]))
+    );
+    assert_eq!(
+        books[0].lookup_path("year"),
+        Some(FieldValue::One("2005".into()))
+    );
+    assert_eq!(books[1].lookup_path("year"), None);
+
+    let keys = [
+        FuseField::new("title"),
+        FuseField::new("authors.name"),
+        FuseField::new("year"),
+    ];
+    let fuse = Fuse::default();
+    let results = fuse.search_text_in_records("gaiman", &books, &keys);
+    assert_eq!(results[0].index, 1);
+    assert_eq!(results[0].results[0].value, "authors.name");
+    assert_eq!(results[0].results[0].value_index, 1);
+    assert_eq!(results[0].results[0].ranges.first(), Some(&(5..11)));
+}
+
+#[cfg(feature = "capi")]
+#[test]
+fn capi_ownership() {
+    use crate::capi::*;
+    use std::ffi::CString;
+
+    let list = ["Old Man's War", "The Lock Artist"].map(|book| CString::new(book).unwrap());
+    let pointers = list.each_ref().map(|book| book.as_ptr());
+    let text = CString::new("war").unwrap();
+    unsafe {
+        let fuse = fuse_new();
+        assert_eq!(fuse_set_threshold(fuse, 2.0), FuseStatus::InvalidConfig);
+        assert_eq!(fuse_set_threshold(fuse, 0.2), FuseStatus::Ok);
+
+        let mut results = std::ptr::null_mut();
+        let status = fuse_search_strings(fuse, text.as_ptr(), pointers.as_ptr(), 2, &mut results);
+        assert_eq!(status, FuseStatus::Ok);
+        fuse_free(fuse);
+
+        assert_eq!(fuse_results_len(results), 1);
+        let mut result = std::mem::zeroed();
+        assert_eq!(fuse_results_get(results, 0, &mut result), FuseStatus::Ok);
+        assert_eq!(result.index, 0);
+        let ranges = std::slice::from_raw_parts(result.ranges, result.ranges_len);
+        assert_eq!(ranges, [FuseRange { start: 10, end: 13 }]);
+        assert_eq!(
+            fuse_results_get(results, 1, &mut result),
+            FuseStatus::OutOfBounds
+        );
+        fuse_results_free(results);
+    }
+}
+
+mod properties {
+    use crate::{Fuse, FuseProperty, Fuseable, Key};
+    use proptest::prelude::*;
+
+    #[derive(Debug)]
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Fuseable for Book {
+        fn properties(&self) -> Vec<FuseProperty> {
+            vec![
+                FuseProperty::init_with_weight("title", 0.3),
+                FuseProperty::init("author"),
+            ]
+        }
+
+        fn lookup(&self, key: &str) -> Option<&str> {
+            match key {
+                "title" => Some(&self.title),
+                "author" => Some(&self.author),
+                _ => None,
+            }
+        }
+    }
+
+    fn fuse(threshold: f64, tokenize: bool) -> Fuse {
+        Fuse {
+            threshold,
+            tokenize,
+            ..Default::default()
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn string_searches_agree(
+            threshold in 0.0..=1.0,
+            tokenize in any::<bool>(),
+            list in prop::collection::vec("[abcAB ]{0,16}", 0..40),
+            query in "[abcAB ]{1,8}",
+            chunk_size in prop::option::of(0usize..50),
+        ) {
+            let fuse = fuse(threshold, tokenize);
+            let list: Vec<&str> = list.iter().map(String::as_str).collect();
+            let expected = fuse.search_text_in_iterable(&query, &list);
+
+            prop_assert_eq!(&fuse.search_many(&[&query], &list)[0], &expected);
+            #[cfg(feature = "rayon")]
+            fuse.search_text_in_string_list_rayon(&query, &list, chunk_size, &|results| {
+                assert_eq!(results, expected);
+            });
+            #[cfg(feature = "async")]
+            fuse.search_text_in_string_list(&query, &list, chunk_size, &|results| {
+                assert_eq!(results, expected);
+            });
+            let _ = chunk_size;
+        }
+
+        #[test]
+        fn fuseable_searches_agree(
+            threshold in 0.0..=1.0,
+            tokenize in any::<bool>(),
+            list in prop::collection::vec(("[abcAB ]{0,16}", "[abcAB ]{0,8}"), 0..40),
+            query in "[abcAB ]{1,8}",
+            chunk_size in prop::option::of(0usize..50),
+        ) {
+            let fuse = fuse(threshold, tokenize);
+            let list: Vec<Book> = list
+                .into_iter()
+                .map(|(title, author)| Book { title, author })
+                .collect();
+            let keys = [
+                Key::new("title", 0.3, |book: &Book| &book.title),
+                Key::new("author", 1.0, |book: &Book| &book.author),
+            ];
+            let expected = fuse.search_text_in_fuse_list(&query, &list);
+
+            prop_assert_eq!(&fuse.search_by_keys(&query, &list, &keys), &expected);
+            #[cfg(feature = "rayon")]
+            {
+                fuse.search_text_in_fuse_list_with_chunk_size_rayon(&query, &list, chunk_size, &|results| {
+                    assert_eq!(results, expected);
+                });
+                fuse.search_by_keys_with_chunk_size_rayon(&query, &list, &keys, chunk_size, &|results| {
+                    assert_eq!(results, expected);
+                });
+            }
+            #[cfg(feature = "async")]
+            fuse.search_text_in_fuse_list_with_chunk_size(&query, &list, chunk_size, &|results| {
+                assert_eq!(results, expected);
+            });
+            let _ = chunk_size;
+        }
+    }
+}
+
+#[test]
+fn pattern_is_reusable() {
+    fn assert_reusable<T: Clone + Send + Sync + 'static>() {}
+    assert_reusable::<crate::Pattern>();
+
+    let fuse = Fuse::default();
+    let pattern = fuse.create_pattern("live").unwrap();
+    let cloned = pattern.clone();
+    let handle = std::thread::spawn(move || Fuse::default().search(Some(&cloned), "Live2"));
+
+    assert_eq!(handle.join().unwrap(), fuse.search(Some(&pattern), "Live2"));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_find_matches_scalar() {
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    for _ in 0..2000 {
+        let haystack = rng.string(b"abAB c", 40);
+        let needle = rng.string(b"abAB c", 4);
+        if needle.is_empty() {
+            continue;
+        }
+        for is_case_sensitive in [false, true] {
+            let needle = if is_case_sensitive {
+                needle.clone()
+            } else {
+                needle.to_ascii_lowercase()
+            };
+            assert_eq!(
+                crate::simd::find_folded(haystack.as_bytes(), needle.as_bytes(), is_case_sensitive),
+                crate::utils::find_folded(
+                    haystack.as_bytes(),
+                    needle.as_bytes(),
+                    is_case_sensitive
+                ),
+                "{:?} in {:?}",
+                needle,
+                haystack
+            );
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_filter_matches_full_scan() {
+    let mut rng = Rng(0x853c49e6748fea9b);
+    let list: Vec<String> = (0..300).map(|_| rng.string(b"abcdeAB xy", 48)).collect();
+
+    for threshold in [0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+        for (tokenize, is_case_sensitive, find_all_matches) in [
+            (false, false, false),
+            (true, false, false),
+            (false, true, false),
+            (false, false, true),
+        ] {
+            let fuse = Fuse {
+                threshold,
+                tokenize,
+                is_case_sensitive,
+                find_all_matches,
+                location: (rng.next() % 8) as i32,
+                distance: [10, 100, 1000][(rng.next() % 3) as usize],
+                ..Default::default()
+            };
+            let mut queries: Vec<String> = (0..8).map(|_| rng.string(b"abcdeAB xy", 32)).collect();
+            // Patterns longer than the 64 bits of the filter state.
+            queries.push("a".repeat(70));
+            queries.push("abc de".repeat(12));
+            for query in queries {
+                let pattern = fuse.create_pattern(&query);
+                let mut searcher = fuse.searcher();
+                let expected: Vec<_> = list
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        let result = searcher.search(pattern.as_ref(), item)?;
+                        Some(crate::SearchResult {
+                            index,
+                            score: result.score,
+                            ranges: result.ranges,
+                        })
+                    })
+                    .collect();
+                assert_eq!(
+                    sorted(fuse.search_text_in_iterable(&query, &list)),
+                    expected,
+                    "query {:?}, threshold {}",
+                    query,
+                    threshold
+                );
+            }
+        }
+    }
+}
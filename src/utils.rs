@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::ops::Range;
 
 pub fn calculate_score(pattern_length: usize, e: i32, x: i32, loc: i32, distance: i32) -> f64 {
@@ -10,38 +9,65 @@ pub fn calculate_score(pattern_length: usize, e: i32, x: i32, loc: i32, distance
     accuracy + (proximity as f64) / (distance as f64)
 }
 
+/// Applies the weight of a `FuseProperty` to the score of its value.
+/// - Parameter score: The score of the value.
+/// - Parameter weight: The weight of the property.
+/// - Returns: The factor applied to the score and the weighted score.
+pub fn weigh_score(score: f64, weight: f64) -> (f64, f64) {
+    let factor = if (weight - 1.0).abs() < 0.00001 {
+        1.0
+    } else {
+        1.0 - weight
+    };
+    let score = if score == 0.0 && (factor - 1.0).abs() < f64::EPSILON {
+        0.001
+    } else {
+        score
+    } * factor;
+    (factor, score)
+}
+
 /// Initializes the alphabet for the Bitap algorithm
-/// - Parameter pattern: The text to encode.
-/// - Returns: Hashmap of character locations.
-pub fn calculate_pattern_alphabet(pattern: &[u8]) -> HashMap<u8, u64> {
+/// - Parameter pattern: The text to encode, only its last 64 bytes fit in the table.
+/// - Returns: Table of character locations, indexed by byte.
+pub fn calculate_pattern_alphabet(pattern: &[u8]) -> Box<[u64; 256]> {
     let len = pattern.len();
-    let mut mask = HashMap::new();
+    let mut mask = Box::new([0; 256]);
     for (i, &c) in pattern.iter().enumerate() {
-        mask.insert(c, mask.get(&c).unwrap_or(&0) | (1 << (len - i - 1)));
+        mask[c as usize] |= 1_u64.checked_shl((len - i - 1) as u32).unwrap_or(0);
     }
     mask
 }
 
-/// Returns an array of `Range<usize>`, where each range represents a consecutive list of `1`s.
-/// - Parameter mask: A string representing the value to search for.
+/// Merges the positions of matched characters into ranges of bytes.
+/// - Parameter positions: Sorted indices of the matched characters.
+/// - Parameter offsets: The byte range of every character in the string, shared by the characters a character was folded to.
 /// - Returns: `Vec<Range<usize>`.
-pub fn find_ranges(mask: &[u8]) -> Result<Vec<Range<usize>>, String> {
-    if mask.is_empty() {
-        return Err(String::from("Input array is empty"));
-    }
-    let mut ranges = vec![];
-    let mut start: i32 = -1;
-    for (n, bit) in mask.iter().enumerate() {
-        if start == -1 && *bit >= 1 {
-            start = n as i32;
-        } else if start != -1 && *bit == 0 {
-            ranges.push(start as usize..n);
-            start = -1;
+pub fn positions_to_ranges(positions: &[usize], offsets: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for &position in positions {
+        let offset = &offsets[position];
+        match ranges.last_mut() {
+            Some(last) if last.end >= offset.start => last.end = last.end.max(offset.end),
+            _ => ranges.push(offset.clone()),
         }
     }
+    ranges
+}
 
-    if *mask.last().unwrap() == 1 {
-        ranges.push(start as usize..mask.len())
-    }
-    Ok(ranges)
+/// Finds the first occurrence of a needle in a haystack, comparing the haystack in lowercase unless case sensitive.
+/// - Parameter haystack: The bytes to search in.
+/// - Parameter needle: The bytes to search for, already lowercase unless case sensitive.
+/// - Returns: The position of the first occurrence, if any.
+#[cfg_attr(feature = "simd", allow(dead_code))]
+pub fn find_folded(haystack: &[u8], needle: &[u8], is_case_sensitive: bool) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| {
+        window.iter().zip(needle).all(|(&a, &b)| {
+            if is_case_sensitive {
+                a == b
+            } else {
+                a.to_ascii_lowercase() == b
+            }
+        })
+    })
 }
@@ -0,0 +1,102 @@
+//! Checks that a `Searcher` doesn't allocate once its buffers have grown.
+//! The allocator counts the allocations of the whole process, so this runs without the test harness, on one thread.
+
+use fuse_rust::Fuse;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts the allocations made by the process.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by `f`.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+/// Small xorshift generator, so the list is reproducible.
+fn list() -> Vec<String> {
+    let mut state = 0x9e3779b97f4a7c15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let alphabet = b"qwrtyuiop QWRTY";
+    (0..1000)
+        .map(|_| {
+            let len = next() % 65;
+            (0..len)
+                .map(|_| alphabet[(next() % alphabet.len() as u64) as usize] as char)
+                .collect()
+        })
+        .collect()
+}
+
+fn main() {
+    let list = list();
+
+    for (tokenize, min_match_char_length) in [(false, 1), (true, 1), (false, 2)] {
+        let fuse = Fuse {
+            tokenize,
+            min_match_char_length,
+            ..Default::default()
+        };
+        let mismatch = fuse.create_pattern("needle lens");
+        let pattern = fuse.create_pattern("qwr");
+        let mut searcher = fuse.searcher();
+        // Let the buffers grow to the size of the largest item first.
+        for item in &list {
+            searcher.search(mismatch.as_ref(), item);
+            searcher.search(pattern.as_ref(), item);
+        }
+
+        let count = allocations(|| {
+            for item in &list {
+                assert!(searcher.search(mismatch.as_ref(), item).is_none());
+            }
+        });
+        assert_eq!(count, 0, "searching strings that don't match allocated");
+
+        let count = allocations(|| {
+            for item in &list {
+                searcher.score(pattern.as_ref(), item);
+            }
+        });
+        assert_eq!(count, 0, "scoring allocated");
+
+        // Matches only allocate the ranges they return, the alignments reuse the buffers of the searcher.
+        let mut matched = 0;
+        let count = allocations(|| {
+            for item in &list {
+                if let Some(result) = searcher.search(pattern.as_ref(), item) {
+                    matched += usize::from(!result.ranges.is_empty());
+                }
+            }
+        });
+        assert!(matched > 0);
+        assert_eq!(
+            count, matched,
+            "searching strings that match allocated more than their ranges"
+        );
+    }
+    println!("allocations: ok");
+}
@@ -0,0 +1,131 @@
+/* Exercises the C API and its memory ownership, run with tests/capi/run.sh. */
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "fuse_rust.h"
+
+static const char *BOOKS[] = {
+    "The Silmarillion",
+    "The Lock Artist",
+    "The Lost Symbol",
+    "Old Man's War",
+};
+static const size_t BOOKS_LEN = sizeof(BOOKS) / sizeof(BOOKS[0]);
+
+static void search_is_sorted_with_ranges(void) {
+    Fuse *fuse = fuse_new();
+    assert(fuse != NULL);
+
+    FuseResults *results = NULL;
+    assert(fuse_search_strings(fuse, "te silm", BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_OK);
+    size_t len = fuse_results_len(results);
+    assert(len > 0);
+
+    FuseResult first;
+    assert(fuse_results_get(results, 0, &first) == FUSE_STATUS_OK);
+    assert(first.index == 0);
+    assert(first.ranges_len > 0);
+    for (size_t i = 0; i < first.ranges_len; i++) {
+        assert(first.ranges[i].start < first.ranges[i].end);
+        assert(first.ranges[i].end <= strlen(BOOKS[first.index]));
+    }
+
+    double previous = first.score;
+    for (size_t i = 1; i < len; i++) {
+        FuseResult result;
+        assert(fuse_results_get(results, i, &result) == FUSE_STATUS_OK);
+        assert(result.score >= previous);
+        previous = result.score;
+    }
+
+    FuseResult past_end;
+    assert(fuse_results_get(results, len, &past_end) == FUSE_STATUS_OUT_OF_BOUNDS);
+
+    /* results own their data, they outlive the fuse instance they came from */
+    fuse_free(fuse);
+    assert(fuse_results_get(results, 0, &first) == FUSE_STATUS_OK);
+    assert(first.ranges[0].start < first.ranges[0].end);
+    fuse_results_free(results);
+}
+
+static void ranges_are_byte_offsets(void) {
+    Fuse *fuse = fuse_new();
+    const char *list[] = {"\xF0\x9F\x98\x80 Old Man's War"};
+
+    FuseResults *results = NULL;
+    assert(fuse_search_strings(fuse, "war", list, 1, &results) == FUSE_STATUS_OK);
+    assert(fuse_results_len(results) == 1);
+
+    FuseResult result;
+    assert(fuse_results_get(results, 0, &result) == FUSE_STATUS_OK);
+    assert(result.ranges_len == 1);
+    assert(result.ranges[0].start == 15);
+    assert(result.ranges[0].end == 18);
+    assert(strncmp(list[0] + result.ranges[0].start, "War", 3) == 0);
+
+    fuse_results_free(results);
+    fuse_free(fuse);
+}
+
+static void setters_validate(void) {
+    Fuse *fuse = fuse_new();
+    assert(fuse_set_threshold(fuse, 1.5) == FUSE_STATUS_INVALID_CONFIG);
+    assert(fuse_set_distance(fuse, -1) == FUSE_STATUS_INVALID_CONFIG);
+    assert(fuse_set_location(fuse, -1) == FUSE_STATUS_INVALID_CONFIG);
+    assert(fuse_set_threshold(fuse, 0.2) == FUSE_STATUS_OK);
+    assert(fuse_set_is_case_sensitive(fuse, true) == FUSE_STATUS_OK);
+    assert(fuse_set_tokenize(fuse, true) == FUSE_STATUS_OK);
+
+    /* a low, case sensitive threshold only matches the same casing */
+    FuseResults *results = NULL;
+    assert(fuse_search_strings(fuse, "war", BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_OK);
+    assert(fuse_results_len(results) == 0);
+    fuse_results_free(results);
+
+    assert(fuse_search_strings(fuse, "War", BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_OK);
+    assert(fuse_results_len(results) == 1);
+    fuse_results_free(results);
+
+    fuse_free(fuse);
+}
+
+static void invalid_arguments(void) {
+    Fuse *fuse = fuse_new();
+    FuseResults *results = NULL;
+
+    assert(fuse_set_threshold(NULL, 0.5) == FUSE_STATUS_NULL_POINTER);
+    assert(fuse_search_strings(NULL, "war", BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_NULL_POINTER);
+    assert(fuse_search_strings(fuse, NULL, BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_NULL_POINTER);
+    assert(fuse_search_strings(fuse, "war", NULL, BOOKS_LEN, &results) == FUSE_STATUS_NULL_POINTER);
+    assert(fuse_search_strings(fuse, "war", BOOKS, BOOKS_LEN, NULL) == FUSE_STATUS_NULL_POINTER);
+
+    const char *with_null[] = {"Old Man's War", NULL};
+    assert(fuse_search_strings(fuse, "war", with_null, 2, &results) == FUSE_STATUS_NULL_POINTER);
+    const char *invalid[] = {"Old Man's \xFF War"};
+    assert(fuse_search_strings(fuse, "war", invalid, 1, &results) == FUSE_STATUS_INVALID_UTF8);
+    assert(fuse_search_strings(fuse, "\xFF", BOOKS, BOOKS_LEN, &results) == FUSE_STATUS_INVALID_UTF8);
+    /* failed searches leave the out pointer untouched */
+    assert(results == NULL);
+
+    assert(fuse_search_strings(fuse, "war", NULL, 0, &results) == FUSE_STATUS_OK);
+    assert(fuse_results_len(results) == 0);
+    fuse_results_free(results);
+
+    assert(fuse_results_len(NULL) == 0);
+    FuseResult result;
+    assert(fuse_results_get(NULL, 0, &result) == FUSE_STATUS_NULL_POINTER);
+    fuse_results_free(NULL);
+    fuse_free(NULL);
+    fuse_free(fuse);
+}
+
+int main(void) {
+    search_is_sorted_with_ranges();
+    ranges_are_byte_offsets();
+    setters_validate();
+    invalid_arguments();
+    puts("capi harness: ok");
+    return 0;
+}
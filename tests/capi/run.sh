@@ -0,0 +1,14 @@
+#!/bin/sh
+# Builds the C API as a shared library and runs the C harness against it,
+# with AddressSanitizer catching leaks, double frees and use after free of the returned results.
+set -eu
+
+cd "$(dirname "$0")/../.."
+cargo rustc --release --features capi --crate-type cdylib
+cbindgen --quiet --output target/fuse_rust.h
+diff -u include/fuse_rust.h target/fuse_rust.h
+
+${CC:-cc} -std=c99 -Wall -Wextra -Werror -g -fsanitize=address,undefined \
+    -Iinclude tests/capi/harness.c -Ltarget/release -lfuse_rust \
+    -o target/capi-harness
+LD_LIBRARY_PATH=target/release ASAN_OPTIONS=detect_leaks=1 target/capi-harness
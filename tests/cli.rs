@@ -0,0 +1,86 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const BOOKS: &str = "The Silmarillion\nThe Lock Artist\nThe Lost Symbol\nOld Man's War\n";
+
+fn fuse(args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fuse"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(BOOKS.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn ranked_lines() {
+    let output = fuse(&["od mn war", "--threshold", "0.5"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Old Man's War\n");
+
+    let output = fuse(&["the", "-n", "2", "--scores"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(stdout.starts_with("0.0000\tThe Silmarillion\n"));
+}
+
+#[test]
+fn highlighted_ranges() {
+    let output = fuse(&["lost", "--color", "always", "-t", "0.1"]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "The \x1b[1;31mLost\x1b[0m Symbol\n"
+    );
+}
+
+#[test]
+fn highlighted_token_ranges() {
+    // the ranges of the tokens arrive out of order, and overlap the one of the whole query
+    let output = fuse(&["symbol lost", "--tokenize", "--color=always", "-t", "0.4"]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "The \x1b[1;31mLost\x1b[0m \x1b[1;31mSymbol\x1b[0m\n"
+    );
+
+    let output = fuse(&["the lost symbol", "--tokenize", "--color=always", "-n", "1"]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "\x1b[1;31mThe Lost Symbol\x1b[0m\n"
+    );
+}
+
+#[test]
+fn json_lines() {
+    let output = fuse(&["lost", "--json", "-t", "0.1"]);
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "line_number": 3,
+            "line": "The Lost Symbol",
+            "score": 0.04,
+            "ranges": [[4, 8]],
+        })
+    );
+}
+
+#[test]
+fn exit_codes() {
+    assert_eq!(fuse(&["zzzzzz", "-t", "0.1"]).status.code(), Some(1));
+
+    let output = fuse(&["war", "--threshold", "1.5"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Invalid Threshold"));
+}